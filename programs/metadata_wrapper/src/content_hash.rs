@@ -0,0 +1,50 @@
+use {
+    crate::{ContentHash, ErrorCode},
+    anchor_lang::prelude::*,
+    mpl_token_metadata::state::Metadata,
+};
+
+// whether an update to the given uri changes the one stored by metaplex, without its null padding
+pub fn uri_changed(metadata: &Metadata, uri: &str) -> bool {
+    metadata.data.uri.trim_matches(char::from(0)) != uri
+}
+
+// stores the sha-256 of the off-chain json of a mint. like history, the content hash account of
+// the mint is passed in the remaining accounts. it's required when a hash is given. when the uri
+// changes without one, a hash of the json behind the old uri is stale, so it's cleared if the
+// account is passed and has been initialized.
+pub fn record<'info>(
+    remaining_accounts: &[AccountInfo<'info>],
+    mint: &Pubkey,
+    hash: Option<[u8; 32]>,
+    uri_changed: bool,
+) -> ProgramResult {
+    if hash.is_none() && !uri_changed {
+        return Ok(());
+    }
+
+    let (content_hash_key, _) =
+        Pubkey::find_program_address(&[ContentHash::SEED, mint.as_ref()], &crate::ID);
+
+    let content_hash_info = match remaining_accounts
+        .iter()
+        .find(|a| a.key() == content_hash_key)
+    {
+        Some(content_hash_info) => content_hash_info,
+        None if hash.is_some() => return Err(ErrorCode::ContentHashAccountMissing.into()),
+        None => return Ok(()),
+    };
+    // not initialized; there's no hash to clear
+    if hash.is_none() && content_hash_info.owner != &crate::ID {
+        return Ok(());
+    }
+    if !content_hash_info.is_writable {
+        return Err(ErrorCode::ContentHashNotWritable.into());
+    }
+
+    let mut content_hash: Account<ContentHash> = Account::try_from(content_hash_info)?;
+    content_hash.hash = hash;
+    content_hash.slot = Clock::get()?.slot;
+
+    content_hash.exit(&crate::ID)
+}
@@ -1,86 +1,2017 @@
 use {
     crate::{
         instructions::{
-            create_master_edition::CreateMasterEdition, create_metadata::CreateMetadata,
-            sign_metadata::SignMetadata, update_metadata::UpdateMetadata,
+            burn_nft::BurnNft,
+            collect_fee::{CollectFee, CollectTokenFee},
+            create_master_edition::CreateMasterEdition,
+            create_metadata::CreateMetadata,
+            print_edition::PrintEdition,
+            sign_metadata::SignMetadata,
+            update_metadata::UpdateMetadata,
+            use_authority::UseAuthority,
+            utilize::Utilize,
+            verify_collection::{SetAndVerifyCollection, VerifyCollection},
         },
-        MetadataWrapper,
+        Allowlist, AuthorityVault, ClaimStatus, CollectionCounter, CollectionDrop,
+        CollectionTemplate, Config, ContentHash, DropInfo, ErrorCode, InitializeConfigInfo,
+        MetadataHistory, MetadataTemplate, MetadataWrapper, Multisig, MultisigProposal,
+        PendingUpdateAuthority, QueuedMetadataUpdate, RevealCommitment, Timelock,
     },
-    anchor_lang::{prelude::*, solana_program::system_program},
-    anchor_spl::token::{Mint, Token},
+    anchor_lang::{
+        prelude::*,
+        solana_program::{program_option::COption, system_program},
+    },
+    anchor_spl::{
+        associated_token::AssociatedToken,
+        token::{Mint, MintTo, Token, TokenAccount},
+    },
+    mpl_token_metadata::pda::{find_master_edition_account, find_metadata_account},
 };
 
-#[derive(Accounts)]
-pub struct GenerateContext<'info> {
-    #[account(mut)]
-    pub payer: Signer<'info>,
+#[derive(Accounts)]
+pub struct GenerateContext<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub mint_authority: Signer<'info>,
+
+    pub update_authority: Signer<'info>,
+
+    /// CHECK: receives the generate fee, any account set in the config
+    #[account(
+        mut,
+        constraint = treasury.key() == unpaused.config.treasury @ ErrorCode::InvalidTreasury,
+    )]
+    pub treasury: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        constraint = mint.decimals == 0 @ ErrorCode::MintNotNft,
+        constraint = mint.supply == 1 @ ErrorCode::MintNotNft,
+        constraint = mint.freeze_authority.is_some() @ ErrorCode::MissingFreezeAuthority,
+        constraint = mint.mint_authority.is_some() @ ErrorCode::MissingMintAuthority,
+        constraint = mint.freeze_authority == COption::Some(update_authority.key()) @ ErrorCode::InvalidFreezeAuthority,
+        constraint = mint.mint_authority == COption::Some(update_authority.key()) @ ErrorCode::InvalidMintAuthority,
+    )]
+    pub mint: Account<'info, Mint>,
+
+    /// CHECK: derivation is verified against the metaplex metadata program, rest via cpi
+    #[account(
+        mut,
+        constraint = metadata.key() == find_metadata_account(&mint.key()).0 @ ErrorCode::InvalidMetadataKey,
+    )]
+    pub metadata: AccountInfo<'info>,
+
+    /// CHECK: derivation is verified against the metaplex metadata program, rest via cpi
+    #[account(
+        mut,
+        constraint = master_edition.key() == find_master_edition_account(&mint.key()).0 @ ErrorCode::InvalidMasterEditionKey,
+    )]
+    pub master_edition: AccountInfo<'info>,
+
+    /// CHECK: verified via cpi into the metaplex metadata program
+    #[account(address = mpl_token_metadata::id())]
+    pub token_metadata_program: UncheckedAccount<'info>,
+
+    #[account(address = spl_token::id())]
+    pub token_program: Program<'info, Token>,
+
+    #[account(address = system_program::id())]
+    pub system_program: Program<'info, System>,
+
+    pub rent: Sysvar<'info, Rent>,
+
+    pub unpaused: Unpaused<'info>,
+}
+
+#[derive(Accounts)]
+pub struct GenerateWithMintContext<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub mint_authority: Signer<'info>,
+
+    pub update_authority: Signer<'info>,
+
+    /// CHECK: receives the generate fee, any account set in the config
+    #[account(
+        mut,
+        constraint = treasury.key() == unpaused.config.treasury @ ErrorCode::InvalidTreasury,
+    )]
+    pub treasury: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        payer = payer,
+        mint::decimals = 0,
+        mint::authority = mint_authority,
+        mint::freeze_authority = mint_authority,
+    )]
+    pub mint: Account<'info, Mint>,
+
+    /// CHECK: any account can receive the token; only used as the owner of the token account
+    pub recipient: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        payer = payer,
+        associated_token::mint = mint,
+        associated_token::authority = recipient,
+    )]
+    pub token_account: Account<'info, TokenAccount>,
+
+    /// CHECK: derivation is verified against the metaplex metadata program, rest via cpi
+    #[account(
+        mut,
+        constraint = metadata.key() == find_metadata_account(&mint.key()).0 @ ErrorCode::InvalidMetadataKey,
+    )]
+    pub metadata: AccountInfo<'info>,
+
+    /// CHECK: derivation is verified against the metaplex metadata program, rest via cpi
+    #[account(
+        mut,
+        constraint = master_edition.key() == find_master_edition_account(&mint.key()).0 @ ErrorCode::InvalidMasterEditionKey,
+    )]
+    pub master_edition: AccountInfo<'info>,
+
+    /// CHECK: verified via cpi into the metaplex metadata program
+    #[account(address = mpl_token_metadata::id())]
+    pub token_metadata_program: UncheckedAccount<'info>,
+
+    #[account(address = spl_token::id())]
+    pub token_program: Program<'info, Token>,
+
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    #[account(address = system_program::id())]
+    pub system_program: Program<'info, System>,
+
+    pub rent: Sysvar<'info, Rent>,
+
+    pub unpaused: Unpaused<'info>,
+}
+
+#[derive(Accounts)]
+pub struct PrintEditionContext<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub new_mint_authority: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = new_mint.decimals == 0 @ ErrorCode::MintNotNft,
+        constraint = new_mint.supply == 1 @ ErrorCode::MintNotNft,
+    )]
+    pub new_mint: Account<'info, Mint>,
+
+    /// CHECK: derivation is verified against the metaplex metadata program, rest via cpi
+    #[account(
+        mut,
+        constraint = new_metadata.key() == find_metadata_account(&new_mint.key()).0 @ ErrorCode::InvalidMetadataKey,
+    )]
+    pub new_metadata: AccountInfo<'info>,
+
+    /// CHECK: verified via cpi into the metaplex metadata program
+    #[account(mut)]
+    pub new_edition: AccountInfo<'info>,
+
+    /// CHECK: any account can be set as the printed edition's update authority
+    pub new_metadata_update_authority: UncheckedAccount<'info>,
+
+    /// CHECK: derived from the next edition number in the instruction handler
+    #[account(mut)]
+    pub edition_marker: AccountInfo<'info>,
+
+    /// CHECK: derivation is verified against the metaplex metadata program, rest via cpi
+    #[account(
+        mut,
+        constraint = master_edition.key() == find_master_edition_account(&master_mint.key()).0 @ ErrorCode::InvalidMasterEditionKey,
+    )]
+    pub master_edition: AccountInfo<'info>,
+
+    #[account(
+        constraint = master_metadata.mint == master_mint.key() @ ErrorCode::MetadataMintMismatch,
+        constraint = master_metadata.key() == find_metadata_account(&master_mint.key()).0 @ ErrorCode::InvalidMetadataKey,
+    )]
+    pub master_metadata: Account<'info, MetadataWrapper>,
+
+    pub master_mint: Account<'info, Mint>,
+
+    pub token_account_owner: Signer<'info>,
+
+    #[account(
+        constraint = token_account.mint == master_mint.key() @ ErrorCode::InvalidTokenAccount,
+        constraint = token_account.owner == token_account_owner.key() @ ErrorCode::InvalidTokenAccount,
+        constraint = token_account.amount == 1 @ ErrorCode::InvalidTokenAccount,
+    )]
+    pub token_account: Account<'info, TokenAccount>,
+
+    /// CHECK: verified via cpi into the metaplex metadata program
+    #[account(address = mpl_token_metadata::id())]
+    pub token_metadata_program: UncheckedAccount<'info>,
+
+    #[account(address = spl_token::id())]
+    pub token_program: Program<'info, Token>,
+
+    #[account(address = system_program::id())]
+    pub system_program: Program<'info, System>,
+
+    pub rent: Sysvar<'info, Rent>,
+
+    pub unpaused: Unpaused<'info>,
+}
+
+#[derive(Accounts)]
+pub struct SignMetadataContext<'info> {
+    pub creator: Signer<'info>,
+
+    // ties metadata to the mint it was created for
+    #[account(
+        mut,
+        constraint = metadata.key() == find_metadata_account(&metadata.mint).0 @ ErrorCode::InvalidMetadataKey,
+    )]
+    pub metadata: Account<'info, MetadataWrapper>,
+
+    /// CHECK: verified via cpi into the metaplex metadata program
+    #[account(address = spl_token_metadata::id())]
+    pub token_metadata_program: AccountInfo<'info>,
+
+    pub unpaused: Unpaused<'info>,
+}
+
+#[derive(Accounts)]
+pub struct VerifyCollectionContext<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    // can be a pda owned by another program, signing via cpi. metaplex marks it writable.
+    #[account(mut)]
+    pub collection_authority: Signer<'info>,
+
+    // ties metadata to the mint it was created for
+    #[account(
+        mut,
+        constraint = metadata.key() == find_metadata_account(&metadata.mint).0 @ ErrorCode::InvalidMetadataKey,
+    )]
+    pub metadata: Account<'info, MetadataWrapper>,
+
+    pub collection_mint: Account<'info, Mint>,
+
+    #[account(
+        constraint = collection_metadata.mint == collection_mint.key() @ ErrorCode::MetadataMintMismatch,
+        constraint = collection_metadata.key() == find_metadata_account(&collection_mint.key()).0 @ ErrorCode::InvalidMetadataKey,
+    )]
+    pub collection_metadata: Account<'info, MetadataWrapper>,
+
+    /// CHECK: derivation is verified against the metaplex metadata program, rest via cpi
+    #[account(
+        constraint = collection_master_edition.key() == find_master_edition_account(&collection_mint.key()).0 @ ErrorCode::InvalidMasterEditionKey,
+    )]
+    pub collection_master_edition: AccountInfo<'info>,
+
+    /// CHECK: verified via cpi into the metaplex metadata program
+    #[account(address = spl_token_metadata::id())]
+    pub token_metadata_program: AccountInfo<'info>,
+
+    pub unpaused: Unpaused<'info>,
+}
+
+#[derive(Accounts)]
+pub struct SetAndVerifyCollectionContext<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    // can be a pda owned by another program, signing via cpi. metaplex marks it writable.
+    #[account(mut)]
+    pub collection_authority: Signer<'info>,
+
+    /// CHECK: verified via cpi into the metaplex metadata program
+    pub update_authority: AccountInfo<'info>,
+
+    // ties metadata to the mint it was created for
+    #[account(
+        mut,
+        constraint = metadata.key() == find_metadata_account(&metadata.mint).0 @ ErrorCode::InvalidMetadataKey,
+    )]
+    pub metadata: Account<'info, MetadataWrapper>,
+
+    pub collection_mint: Account<'info, Mint>,
+
+    #[account(
+        constraint = collection_metadata.mint == collection_mint.key() @ ErrorCode::MetadataMintMismatch,
+        constraint = collection_metadata.key() == find_metadata_account(&collection_mint.key()).0 @ ErrorCode::InvalidMetadataKey,
+    )]
+    pub collection_metadata: Account<'info, MetadataWrapper>,
+
+    /// CHECK: derivation is verified against the metaplex metadata program, rest via cpi
+    #[account(
+        constraint = collection_master_edition.key() == find_master_edition_account(&collection_mint.key()).0 @ ErrorCode::InvalidMasterEditionKey,
+    )]
+    pub collection_master_edition: AccountInfo<'info>,
+
+    /// CHECK: verified via cpi into the metaplex metadata program
+    #[account(address = spl_token_metadata::id())]
+    pub token_metadata_program: AccountInfo<'info>,
+
+    pub unpaused: Unpaused<'info>,
+}
+
+#[derive(Accounts)]
+pub struct BurnNftContext<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = metadata.mint == mint.key() @ ErrorCode::MetadataMintMismatch,
+        constraint = metadata.key() == find_metadata_account(&mint.key()).0 @ ErrorCode::InvalidMetadataKey,
+    )]
+    pub metadata: Account<'info, MetadataWrapper>,
+
+    #[account(
+        mut,
+        constraint = mint.decimals == 0 @ ErrorCode::MintNotNft,
+    )]
+    pub mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        constraint = token_account.mint == mint.key() @ ErrorCode::InvalidTokenAccount,
+        constraint = token_account.owner == owner.key() @ ErrorCode::InvalidTokenAccount,
+        constraint = token_account.amount == 1 @ ErrorCode::InvalidTokenAccount,
+    )]
+    pub token_account: Account<'info, TokenAccount>,
+
+    /// CHECK: verified via cpi into the metaplex metadata program
+    #[account(
+        mut,
+        constraint = master_edition.key() == find_master_edition_account(&mint.key()).0 @ ErrorCode::InvalidMasterEditionKey,
+    )]
+    pub master_edition: AccountInfo<'info>,
+
+    /// CHECK: any account can receive the reclaimed rent
+    #[account(mut)]
+    pub receiver: UncheckedAccount<'info>,
+
+    /// CHECK: verified via cpi into the metaplex metadata program
+    #[account(address = mpl_token_metadata::id())]
+    pub token_metadata_program: UncheckedAccount<'info>,
+
+    #[account(address = spl_token::id())]
+    pub token_program: Program<'info, Token>,
+
+    #[account(address = system_program::id())]
+    pub system_program: Program<'info, System>,
+
+    pub unpaused: Unpaused<'info>,
+}
+
+#[derive(Accounts)]
+pub struct UtilizeContext<'info> {
+    // owner of the token, or a delegated use authority
+    #[account(mut)]
+    pub use_authority: Signer<'info>,
+
+    /// CHECK: owner of the token account; verified via token account constraint
+    pub owner: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        constraint = metadata.mint == mint.key() @ ErrorCode::MetadataMintMismatch,
+        constraint = metadata.key() == find_metadata_account(&mint.key()).0 @ ErrorCode::InvalidMetadataKey,
+    )]
+    pub metadata: Account<'info, MetadataWrapper>,
+
+    #[account(mut)]
+    pub mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        constraint = token_account.mint == mint.key() @ ErrorCode::InvalidTokenAccount,
+        constraint = token_account.owner == owner.key() @ ErrorCode::InvalidTokenAccount,
+    )]
+    pub token_account: Account<'info, TokenAccount>,
+
+    /// CHECK: derived from mint and use authority in the instruction handler
+    #[account(mut)]
+    pub use_authority_record: UncheckedAccount<'info>,
+
+    /// CHECK: derived in the instruction handler
+    pub burner: UncheckedAccount<'info>,
+
+    /// CHECK: verified via cpi into the metaplex metadata program
+    #[account(address = mpl_token_metadata::id())]
+    pub token_metadata_program: UncheckedAccount<'info>,
+
+    #[account(address = spl_token::id())]
+    pub token_program: Program<'info, Token>,
+
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    #[account(address = system_program::id())]
+    pub system_program: Program<'info, System>,
+
+    pub rent: Sysvar<'info, Rent>,
+
+    pub unpaused: Unpaused<'info>,
+}
+
+#[derive(Accounts)]
+pub struct UseAuthorityContext<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: any account can be approved as a use authority
+    pub user: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        constraint = owner_token_account.mint == mint.key() @ ErrorCode::InvalidTokenAccount,
+        constraint = owner_token_account.owner == owner.key() @ ErrorCode::InvalidTokenAccount,
+    )]
+    pub owner_token_account: Account<'info, TokenAccount>,
+
+    #[account(
+        constraint = metadata.mint == mint.key() @ ErrorCode::MetadataMintMismatch,
+        constraint = metadata.key() == find_metadata_account(&mint.key()).0 @ ErrorCode::InvalidMetadataKey,
+    )]
+    pub metadata: Account<'info, MetadataWrapper>,
+
+    pub mint: Account<'info, Mint>,
+
+    /// CHECK: derived from mint and user in the instruction handler
+    #[account(mut)]
+    pub use_authority_record: UncheckedAccount<'info>,
+
+    /// CHECK: derived in the instruction handler
+    pub burner: UncheckedAccount<'info>,
+
+    /// CHECK: verified via cpi into the metaplex metadata program
+    #[account(address = mpl_token_metadata::id())]
+    pub token_metadata_program: UncheckedAccount<'info>,
+
+    #[account(address = spl_token::id())]
+    pub token_program: Program<'info, Token>,
+
+    #[account(address = system_program::id())]
+    pub system_program: Program<'info, System>,
+
+    pub rent: Sysvar<'info, Rent>,
+
+    pub unpaused: Unpaused<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(new_update_authority: Pubkey, bump: u8)]
+pub struct ProposeUpdateAuthorityContext<'info> {
+    #[account(mut)]
+    pub update_authority: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = metadata.key() == find_metadata_account(&metadata.mint).0 @ ErrorCode::InvalidMetadataKey,
+        constraint = metadata.update_authority == update_authority.key() @ ErrorCode::UpdateAuthorityIncorrect,
+    )]
+    pub metadata: Account<'info, MetadataWrapper>,
+
+    // one pending transfer per metadata; an existing one must be cancelled first
+    #[account(
+        init,
+        payer = update_authority,
+        space = PendingUpdateAuthority::LEN,
+        seeds = [PendingUpdateAuthority::SEED, metadata.key().as_ref()],
+        bump = bump,
+    )]
+    pub pending_update_authority: Account<'info, PendingUpdateAuthority>,
+
+    /// CHECK: verified via cpi into the metaplex metadata program
+    #[account(address = spl_token_metadata::id())]
+    pub token_metadata_program: AccountInfo<'info>,
+
+    #[account(address = system_program::id())]
+    pub system_program: Program<'info, System>,
+
+    pub unpaused: Unpaused<'info>,
+}
+
+#[derive(Accounts)]
+pub struct AcceptUpdateAuthorityContext<'info> {
+    pub new_update_authority: Signer<'info>,
+
+    // authority that proposed the transfer; receives the rent of the pending transfer account
+    /// CHECK: checked against the pending transfer
+    #[account(mut)]
+    pub update_authority: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        constraint = metadata.key() == find_metadata_account(&metadata.mint).0 @ ErrorCode::InvalidMetadataKey,
+    )]
+    pub metadata: Account<'info, MetadataWrapper>,
+
+    #[account(
+        mut,
+        close = update_authority,
+        seeds = [PendingUpdateAuthority::SEED, metadata.key().as_ref()],
+        bump = pending_update_authority.bump,
+        has_one = metadata,
+        has_one = update_authority @ ErrorCode::UpdateAuthorityIncorrect,
+        constraint = pending_update_authority.proposed_update_authority == new_update_authority.key() @ ErrorCode::InvalidProposedUpdateAuthority,
+    )]
+    pub pending_update_authority: Account<'info, PendingUpdateAuthority>,
+
+    /// CHECK: verified via cpi into the metaplex metadata program
+    #[account(address = spl_token_metadata::id())]
+    pub token_metadata_program: AccountInfo<'info>,
+
+    pub unpaused: Unpaused<'info>,
+}
+
+#[derive(Accounts)]
+pub struct CancelUpdateAuthorityTransferContext<'info> {
+    // authority that proposed the transfer; gets the metadata back
+    #[account(mut)]
+    pub update_authority: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = metadata.key() == find_metadata_account(&metadata.mint).0 @ ErrorCode::InvalidMetadataKey,
+    )]
+    pub metadata: Account<'info, MetadataWrapper>,
+
+    #[account(
+        mut,
+        close = update_authority,
+        seeds = [PendingUpdateAuthority::SEED, metadata.key().as_ref()],
+        bump = pending_update_authority.bump,
+        has_one = metadata,
+        has_one = update_authority @ ErrorCode::UpdateAuthorityIncorrect,
+    )]
+    pub pending_update_authority: Account<'info, PendingUpdateAuthority>,
+
+    /// CHECK: verified via cpi into the metaplex metadata program
+    #[account(address = spl_token_metadata::id())]
+    pub token_metadata_program: AccountInfo<'info>,
+
+    pub unpaused: Unpaused<'info>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateMetadataContext<'info> {
+    #[account(mut)]
+    pub update_authority: Signer<'info>,
+
+    // ties metadata to the mint it was created for
+    #[account(
+        mut,
+        constraint = metadata.key() == find_metadata_account(&metadata.mint).0 @ ErrorCode::InvalidMetadataKey,
+    )]
+    pub metadata: Account<'info, MetadataWrapper>,
+
+    /// CHECK: verified via cpi into the metaplex metadata program
+    #[account(address = spl_token_metadata::id())]
+    pub token_metadata_program: AccountInfo<'info>,
+
+    pub unpaused: Unpaused<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(collection: Pubkey, bump: u8)]
+pub struct InitializeAuthorityVaultContext<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    // update authority of the collection nft; becomes the vault authority
+    pub authority: Signer<'info>,
+
+    // only the collection's update authority can set up its vault
+    #[account(
+        constraint = collection_metadata.key() == find_metadata_account(&collection).0 @ ErrorCode::InvalidMetadataKey,
+        constraint = collection_metadata.update_authority == authority.key() @ ErrorCode::UpdateAuthorityIncorrect,
+    )]
+    pub collection_metadata: Account<'info, MetadataWrapper>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = AuthorityVault::LEN,
+        seeds = [AuthorityVault::SEED, collection.as_ref()],
+        bump = bump,
+    )]
+    pub vault: Account<'info, AuthorityVault>,
+
+    #[account(address = system_program::id())]
+    pub system_program: Program<'info, System>,
+
+    pub unpaused: Unpaused<'info>,
+}
+
+#[derive(Accounts)]
+pub struct VaultUpdateMetadataContext<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [AuthorityVault::SEED, vault.collection.as_ref()],
+        bump = vault.bump,
+        has_one = authority @ ErrorCode::InvalidVaultAuthority,
+    )]
+    pub vault: Account<'info, AuthorityVault>,
+
+    // ties metadata to the mint it was created for
+    #[account(
+        mut,
+        constraint = metadata.key() == find_metadata_account(&metadata.mint).0 @ ErrorCode::InvalidMetadataKey,
+        constraint = metadata.update_authority == vault.key() @ ErrorCode::UpdateAuthorityIncorrect,
+    )]
+    pub metadata: Account<'info, MetadataWrapper>,
+
+    /// CHECK: verified via cpi into the metaplex metadata program
+    #[account(address = spl_token_metadata::id())]
+    pub token_metadata_program: AccountInfo<'info>,
+
+    pub unpaused: Unpaused<'info>,
+}
+
+// same as VerifyCollectionContext, for a collection nft held by an authority vault
+#[derive(Accounts)]
+pub struct VaultVerifyCollectionContext<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub authority: Signer<'info>,
+
+    // metaplex marks the collection authority writable
+    #[account(
+        mut,
+        seeds = [AuthorityVault::SEED, vault.collection.as_ref()],
+        bump = vault.bump,
+        has_one = authority @ ErrorCode::InvalidVaultAuthority,
+    )]
+    pub vault: Account<'info, AuthorityVault>,
+
+    // ties metadata to the mint it was created for
+    #[account(
+        mut,
+        constraint = metadata.key() == find_metadata_account(&metadata.mint).0 @ ErrorCode::InvalidMetadataKey,
+    )]
+    pub metadata: Account<'info, MetadataWrapper>,
+
+    pub collection_mint: Account<'info, Mint>,
+
+    #[account(
+        constraint = collection_metadata.mint == collection_mint.key() @ ErrorCode::MetadataMintMismatch,
+        constraint = collection_metadata.key() == find_metadata_account(&collection_mint.key()).0 @ ErrorCode::InvalidMetadataKey,
+        constraint = collection_metadata.update_authority == vault.key() @ ErrorCode::UpdateAuthorityIncorrect,
+    )]
+    pub collection_metadata: Account<'info, MetadataWrapper>,
+
+    /// CHECK: derivation is verified against the metaplex metadata program, rest via cpi
+    #[account(
+        constraint = collection_master_edition.key() == find_master_edition_account(&collection_mint.key()).0 @ ErrorCode::InvalidMasterEditionKey,
+    )]
+    pub collection_master_edition: AccountInfo<'info>,
+
+    /// CHECK: verified via cpi into the metaplex metadata program
+    #[account(address = spl_token_metadata::id())]
+    pub token_metadata_program: AccountInfo<'info>,
+
+    pub unpaused: Unpaused<'info>,
+}
+
+#[derive(Accounts)]
+pub struct SetVaultAuthorityContext<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [AuthorityVault::SEED, vault.collection.as_ref()],
+        bump = vault.bump,
+        has_one = authority @ ErrorCode::InvalidVaultAuthority,
+    )]
+    pub vault: Account<'info, AuthorityVault>,
+
+    pub unpaused: Unpaused<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(owners: Vec<Pubkey>, threshold: u8, bump: u8)]
+pub struct CreateMultisigContext<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    // any fresh key; only used to derive the multisig address
+    pub base: Signer<'info>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = Multisig::LEN,
+        seeds = [Multisig::SEED, base.key().as_ref()],
+        bump = bump,
+    )]
+    pub multisig: Account<'info, Multisig>,
+
+    #[account(address = system_program::id())]
+    pub system_program: Program<'info, System>,
+
+    pub unpaused: Unpaused<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ProposeMultisigUpdateContext<'info> {
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [Multisig::SEED, multisig.base.as_ref()],
+        bump = multisig.bump,
+    )]
+    pub multisig: Account<'info, Multisig>,
+
+    // ties metadata to the mint it was created for
+    #[account(
+        constraint = metadata.key() == find_metadata_account(&metadata.mint).0 @ ErrorCode::InvalidMetadataKey,
+        constraint = metadata.update_authority == multisig.key() @ ErrorCode::UpdateAuthorityIncorrect,
+    )]
+    pub metadata: Account<'info, MetadataWrapper>,
+
+    #[account(
+        init,
+        payer = proposer,
+        space = MultisigProposal::LEN,
+        seeds = [
+            MultisigProposal::SEED,
+            multisig.key().as_ref(),
+            multisig.proposal_count.to_le_bytes().as_ref(),
+        ],
+        bump,
+    )]
+    pub proposal: Account<'info, MultisigProposal>,
+
+    #[account(address = system_program::id())]
+    pub system_program: Program<'info, System>,
+
+    pub unpaused: Unpaused<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ApproveMultisigUpdateContext<'info> {
+    pub owner: Signer<'info>,
+
+    pub multisig: Account<'info, Multisig>,
+
+    #[account(
+        mut,
+        seeds = [
+            MultisigProposal::SEED,
+            multisig.key().as_ref(),
+            proposal.index.to_le_bytes().as_ref(),
+        ],
+        bump,
+        has_one = multisig,
+    )]
+    pub proposal: Account<'info, MultisigProposal>,
+
+    pub unpaused: Unpaused<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ExecuteMultisigUpdateContext<'info> {
+    /// CHECK: only receives the rent of the executed proposal
+    #[account(mut)]
+    pub proposer: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [Multisig::SEED, multisig.base.as_ref()],
+        bump = multisig.bump,
+    )]
+    pub multisig: Account<'info, Multisig>,
+
+    #[account(
+        mut,
+        close = proposer,
+        seeds = [
+            MultisigProposal::SEED,
+            multisig.key().as_ref(),
+            proposal.index.to_le_bytes().as_ref(),
+        ],
+        bump,
+        has_one = multisig,
+        has_one = metadata,
+        has_one = proposer,
+    )]
+    pub proposal: Account<'info, MultisigProposal>,
+
+    #[account(
+        mut,
+        constraint = metadata.update_authority == multisig.key() @ ErrorCode::UpdateAuthorityIncorrect,
+    )]
+    pub metadata: Account<'info, MetadataWrapper>,
+
+    /// CHECK: verified via cpi into the metaplex metadata program
+    #[account(address = spl_token_metadata::id())]
+    pub token_metadata_program: AccountInfo<'info>,
+
+    pub unpaused: Unpaused<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(collection: Pubkey, delay: i64, bump: u8)]
+pub struct InitializeTimelockContext<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    // update authority of the collection nft; becomes the timelock authority
+    pub authority: Signer<'info>,
+
+    // only the collection's update authority can set up its timelock
+    #[account(
+        constraint = collection_metadata.key() == find_metadata_account(&collection).0 @ ErrorCode::InvalidMetadataKey,
+        constraint = collection_metadata.update_authority == authority.key() @ ErrorCode::UpdateAuthorityIncorrect,
+    )]
+    pub collection_metadata: Account<'info, MetadataWrapper>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = Timelock::LEN,
+        seeds = [Timelock::SEED, collection.as_ref()],
+        bump = bump,
+    )]
+    pub timelock: Account<'info, Timelock>,
+
+    #[account(address = system_program::id())]
+    pub system_program: Program<'info, System>,
+
+    pub unpaused: Unpaused<'info>,
+}
+
+#[derive(Accounts)]
+pub struct SetTimelockDelayContext<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = authority @ ErrorCode::InvalidTimelockAuthority,
+    )]
+    pub timelock: Account<'info, Timelock>,
+
+    pub unpaused: Unpaused<'info>,
+}
+
+#[derive(Accounts)]
+pub struct QueueMetadataUpdateContext<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [Timelock::SEED, timelock.collection.as_ref()],
+        bump = timelock.bump,
+        has_one = authority @ ErrorCode::InvalidTimelockAuthority,
+    )]
+    pub timelock: Account<'info, Timelock>,
+
+    // ties metadata to the mint it was created for, and to the collection of the timelock
+    #[account(
+        constraint = metadata.key() == find_metadata_account(&metadata.mint).0 @ ErrorCode::InvalidMetadataKey,
+        constraint = metadata.update_authority == timelock.key() @ ErrorCode::UpdateAuthorityIncorrect,
+        constraint = metadata.collection.as_ref().map(|c| c.key) == Some(timelock.collection) @ ErrorCode::MetadataCollectionMismatch,
+    )]
+    pub metadata: Account<'info, MetadataWrapper>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = QueuedMetadataUpdate::LEN,
+        seeds = [QueuedMetadataUpdate::SEED, metadata.key().as_ref()],
+        bump,
+    )]
+    pub queued_update: Account<'info, QueuedMetadataUpdate>,
+
+    #[account(address = system_program::id())]
+    pub system_program: Program<'info, System>,
+
+    pub unpaused: Unpaused<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ExecuteMetadataUpdateContext<'info> {
+    /// CHECK: only receives the rent of the executed update
+    #[account(mut)]
+    pub authority: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [Timelock::SEED, timelock.collection.as_ref()],
+        bump = timelock.bump,
+    )]
+    pub timelock: Account<'info, Timelock>,
+
+    #[account(
+        mut,
+        constraint = metadata.update_authority == timelock.key() @ ErrorCode::UpdateAuthorityIncorrect,
+    )]
+    pub metadata: Account<'info, MetadataWrapper>,
+
+    #[account(
+        mut,
+        close = authority,
+        seeds = [QueuedMetadataUpdate::SEED, metadata.key().as_ref()],
+        bump,
+        has_one = timelock,
+        has_one = metadata,
+        has_one = authority,
+    )]
+    pub queued_update: Account<'info, QueuedMetadataUpdate>,
+
+    /// CHECK: verified via cpi into the metaplex metadata program
+    #[account(address = spl_token_metadata::id())]
+    pub token_metadata_program: AccountInfo<'info>,
+
+    pub unpaused: Unpaused<'info>,
+}
+
+#[derive(Accounts)]
+pub struct CancelMetadataUpdateContext<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        has_one = authority @ ErrorCode::InvalidTimelockAuthority,
+    )]
+    pub timelock: Account<'info, Timelock>,
+
+    #[account(
+        mut,
+        close = authority,
+        has_one = timelock,
+    )]
+    pub queued_update: Account<'info, QueuedMetadataUpdate>,
+
+    pub unpaused: Unpaused<'info>,
+}
+
+#[derive(Accounts)]
+pub struct InitializeMetadataHistoryContext<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    // ties metadata to the mint it was created for
+    #[account(
+        constraint = metadata.key() == find_metadata_account(&metadata.mint).0 @ ErrorCode::InvalidMetadataKey,
+    )]
+    pub metadata: Account<'info, MetadataWrapper>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = MetadataHistory::LEN,
+        seeds = [MetadataHistory::SEED, metadata.mint.as_ref()],
+        bump,
+    )]
+    pub history: Account<'info, MetadataHistory>,
+
+    #[account(address = system_program::id())]
+    pub system_program: Program<'info, System>,
+
+    pub unpaused: Unpaused<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(info: InitializeConfigInfo, bump: u8)]
+pub struct InitializeConfigContext<'info> {
+    // only the upgrade authority of the program can create the config and become its admin
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        constraint = program.programdata_address() == Some(program_data.key()) @ ErrorCode::InvalidProgramData,
+    )]
+    pub program: Program<'info, crate::program::MetadataWrapper>,
+
+    #[account(
+        constraint = program_data.upgrade_authority_address == Some(admin.key()) @ ErrorCode::InvalidAdmin,
+    )]
+    pub program_data: Account<'info, ProgramData>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = Config::LEN,
+        seeds = [Config::SEED],
+        bump = bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(address = system_program::id())]
+    pub system_program: Program<'info, System>,
+}
+
+// not gated by the pause switch, so that the admin can unpause
+#[derive(Accounts)]
+pub struct UpdateConfigContext<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [Config::SEED],
+        bump = config.bump,
+        has_one = admin @ ErrorCode::InvalidAdmin,
+    )]
+    pub config: Account<'info, Config>,
+}
+
+// global config; every instruction except the config ones fails while the program is paused
+#[derive(Accounts, Clone)]
+pub struct Unpaused<'info> {
+    #[account(
+        seeds = [Config::SEED],
+        bump = config.bump,
+        constraint = !config.paused @ ErrorCode::ProgramPaused,
+    )]
+    pub config: Account<'info, Config>,
+}
+
+#[derive(Accounts)]
+#[instruction(collection: Pubkey, template: MetadataTemplate, bump: u8)]
+pub struct InitializeCollectionTemplateContext<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    // update authority of the collection nft; becomes the template authority and has to sign
+    // every generate_from_template
+    pub authority: Signer<'info>,
+
+    // only the collection's update authority can set up its template
+    #[account(
+        constraint = collection_metadata.key() == find_metadata_account(&collection).0 @ ErrorCode::InvalidMetadataKey,
+        constraint = collection_metadata.update_authority == authority.key() @ ErrorCode::UpdateAuthorityIncorrect,
+    )]
+    pub collection_metadata: Account<'info, MetadataWrapper>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = CollectionTemplate::LEN,
+        seeds = [CollectionTemplate::SEED, collection.as_ref()],
+        bump = bump,
+    )]
+    pub template: Account<'info, CollectionTemplate>,
+
+    #[account(address = system_program::id())]
+    pub system_program: Program<'info, System>,
+
+    pub unpaused: Unpaused<'info>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateCollectionTemplateContext<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [CollectionTemplate::SEED, template.collection.as_ref()],
+        bump = template.bump,
+        has_one = authority @ ErrorCode::InvalidTemplateAuthority,
+    )]
+    pub template: Account<'info, CollectionTemplate>,
+
+    pub unpaused: Unpaused<'info>,
+}
+
+#[derive(Accounts)]
+pub struct GenerateFromTemplateContext<'info> {
+    pub generate: GenerateContext<'info>,
+
+    // template authority generates, so that nobody else can mint into the collection's defaults
+    #[account(
+        seeds = [CollectionTemplate::SEED, template.collection.as_ref()],
+        bump = template.bump,
+        constraint = template.authority == generate.update_authority.key() @ ErrorCode::InvalidTemplateAuthority,
+    )]
+    pub template: Account<'info, CollectionTemplate>,
+}
+
+#[derive(Accounts)]
+#[instruction(collection: Pubkey, max_supply: u64, name_prefix: String, base_uri: String, bump: u8)]
+pub struct InitializeCollectionCounterContext<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    // update authority of the collection nft; becomes the counter authority and has to sign
+    // every generate_numbered
+    pub authority: Signer<'info>,
+
+    // only the collection's update authority can set up its counter
+    #[account(
+        constraint = collection_metadata.key() == find_metadata_account(&collection).0 @ ErrorCode::InvalidMetadataKey,
+        constraint = collection_metadata.update_authority == authority.key() @ ErrorCode::UpdateAuthorityIncorrect,
+    )]
+    pub collection_metadata: Account<'info, MetadataWrapper>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = CollectionCounter::LEN,
+        seeds = [CollectionCounter::SEED, collection.as_ref()],
+        bump = bump,
+    )]
+    pub counter: Account<'info, CollectionCounter>,
+
+    #[account(address = system_program::id())]
+    pub system_program: Program<'info, System>,
+
+    pub unpaused: Unpaused<'info>,
+}
+
+#[derive(Accounts)]
+pub struct GenerateNumberedContext<'info> {
+    pub generate: GenerateContext<'info>,
+
+    // counter authority generates, so that nobody else can take numbers of the collection
+    #[account(
+        mut,
+        seeds = [CollectionCounter::SEED, counter.collection.as_ref()],
+        bump = counter.bump,
+        constraint = counter.authority == generate.update_authority.key() @ ErrorCode::InvalidCounterAuthority,
+    )]
+    pub counter: Account<'info, CollectionCounter>,
+}
+
+#[derive(Accounts)]
+#[instruction(key: Pubkey, root: [u8; 32], reveal_at: i64, bump: u8)]
+pub struct CommitRevealContext<'info> {
+    // update authority of the committed mint or collection nft; becomes the commitment authority
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    // only the update authority of the mint or collection can commit to its uris
+    #[account(
+        constraint = key_metadata.key() == find_metadata_account(&key).0 @ ErrorCode::InvalidMetadataKey,
+        constraint = key_metadata.update_authority == authority.key() @ ErrorCode::UpdateAuthorityIncorrect,
+    )]
+    pub key_metadata: Account<'info, MetadataWrapper>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = RevealCommitment::LEN,
+        seeds = [RevealCommitment::SEED, key.as_ref()],
+        bump = bump,
+    )]
+    pub commitment: Account<'info, RevealCommitment>,
+
+    #[account(address = system_program::id())]
+    pub system_program: Program<'info, System>,
+
+    pub unpaused: Unpaused<'info>,
+}
+
+#[derive(Accounts)]
+pub struct RevealContext<'info> {
+    pub cranker: Signer<'info>,
+
+    #[account(
+        seeds = [RevealCommitment::SEED, commitment.key.as_ref()],
+        bump = commitment.bump,
+    )]
+    pub commitment: Account<'info, RevealCommitment>,
+
+    // metadata has to be held by the commitment, and be the committed mint or part of the
+    // committed collection
+    #[account(
+        mut,
+        constraint = metadata.key() == find_metadata_account(&metadata.mint).0 @ ErrorCode::InvalidMetadataKey,
+        constraint = metadata.update_authority == commitment.key() @ ErrorCode::UpdateAuthorityIncorrect,
+        constraint = metadata.mint == commitment.key
+            || metadata.collection.as_ref().map(|c| c.key) == Some(commitment.key) @ ErrorCode::MetadataCollectionMismatch,
+    )]
+    pub metadata: Account<'info, MetadataWrapper>,
+
+    /// CHECK: verified via cpi into the metaplex metadata program
+    #[account(address = spl_token_metadata::id())]
+    pub token_metadata_program: AccountInfo<'info>,
+
+    pub unpaused: Unpaused<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ReleaseRevealAuthorityContext<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [RevealCommitment::SEED, commitment.key.as_ref()],
+        bump = commitment.bump,
+        has_one = authority @ ErrorCode::InvalidRevealAuthority,
+    )]
+    pub commitment: Account<'info, RevealCommitment>,
+
+    // ties metadata to the mint it was created for
+    #[account(
+        mut,
+        constraint = metadata.key() == find_metadata_account(&metadata.mint).0 @ ErrorCode::InvalidMetadataKey,
+        constraint = metadata.update_authority == commitment.key() @ ErrorCode::UpdateAuthorityIncorrect,
+    )]
+    pub metadata: Account<'info, MetadataWrapper>,
+
+    /// CHECK: verified via cpi into the metaplex metadata program
+    #[account(address = spl_token_metadata::id())]
+    pub token_metadata_program: AccountInfo<'info>,
+
+    pub unpaused: Unpaused<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(collection: Pubkey, root: [u8; 32], bump: u8)]
+pub struct InitializeAllowlistContext<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    // update authority of the collection nft; becomes the allowlist authority, and the update
+    // authority of every allowlisted nft
+    pub authority: Signer<'info>,
+
+    // only the collection's update authority can set up its allowlist
+    #[account(
+        constraint = collection_metadata.key() == find_metadata_account(&collection).0 @ ErrorCode::InvalidMetadataKey,
+        constraint = collection_metadata.update_authority == authority.key() @ ErrorCode::UpdateAuthorityIncorrect,
+    )]
+    pub collection_metadata: Account<'info, MetadataWrapper>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = Allowlist::LEN,
+        seeds = [Allowlist::SEED, collection.as_ref()],
+        bump = bump,
+    )]
+    pub allowlist: Account<'info, Allowlist>,
+
+    #[account(address = system_program::id())]
+    pub system_program: Program<'info, System>,
+
+    pub unpaused: Unpaused<'info>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateAllowlistRootContext<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [Allowlist::SEED, allowlist.collection.as_ref()],
+        bump = allowlist.bump,
+        has_one = authority @ ErrorCode::InvalidAllowlistAuthority,
+    )]
+    pub allowlist: Account<'info, Allowlist>,
+
+    pub unpaused: Unpaused<'info>,
+}
+
+#[derive(Accounts)]
+pub struct GenerateAllowlistedContext<'info> {
+    // allowlisted wallet; pays the generate fee and rent, and receives the nft
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    // the allowlist pda signs as mint and update authority, so that allowlisted nfts can only be
+    // created with a proof and within the limits
+    #[account(
+        seeds = [Allowlist::SEED, allowlist.collection.as_ref()],
+        bump = allowlist.bump,
+    )]
+    pub allowlist: Account<'info, Allowlist>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = ClaimStatus::LEN,
+        seeds = [
+            ClaimStatus::SEED,
+            allowlist.key().as_ref(),
+            payer.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub claim_status: Account<'info, ClaimStatus>,
+
+    /// CHECK: receives the generate fee, any account set in the config
+    #[account(
+        mut,
+        constraint = treasury.key() == unpaused.config.treasury @ ErrorCode::InvalidTreasury,
+    )]
+    pub treasury: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        payer = payer,
+        mint::decimals = 0,
+        mint::authority = allowlist,
+        mint::freeze_authority = allowlist,
+    )]
+    pub mint: Account<'info, Mint>,
+
+    #[account(
+        init,
+        payer = payer,
+        associated_token::mint = mint,
+        associated_token::authority = payer,
+    )]
+    pub token_account: Account<'info, TokenAccount>,
+
+    /// CHECK: derivation is verified against the metaplex metadata program, rest via cpi
+    #[account(
+        mut,
+        constraint = metadata.key() == find_metadata_account(&mint.key()).0 @ ErrorCode::InvalidMetadataKey,
+    )]
+    pub metadata: AccountInfo<'info>,
+
+    /// CHECK: derivation is verified against the metaplex metadata program, rest via cpi
+    #[account(
+        mut,
+        constraint = master_edition.key() == find_master_edition_account(&mint.key()).0 @ ErrorCode::InvalidMasterEditionKey,
+    )]
+    pub master_edition: AccountInfo<'info>,
+
+    /// CHECK: verified via cpi into the metaplex metadata program
+    #[account(address = mpl_token_metadata::id())]
+    pub token_metadata_program: UncheckedAccount<'info>,
+
+    #[account(address = spl_token::id())]
+    pub token_program: Program<'info, Token>,
+
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    #[account(address = system_program::id())]
+    pub system_program: Program<'info, System>,
+
+    pub rent: Sysvar<'info, Rent>,
+
+    pub unpaused: Unpaused<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(collection: Pubkey, info: DropInfo, bump: u8)]
+pub struct InitializeDropContext<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    // update authority of the collection nft; becomes the drop authority
+    pub authority: Signer<'info>,
+
+    // only the collection's update authority can set up its drop
+    #[account(
+        constraint = collection_metadata.key() == find_metadata_account(&collection).0 @ ErrorCode::InvalidMetadataKey,
+        constraint = collection_metadata.update_authority == authority.key() @ ErrorCode::UpdateAuthorityIncorrect,
+    )]
+    pub collection_metadata: Account<'info, MetadataWrapper>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = CollectionDrop::LEN,
+        seeds = [CollectionDrop::SEED, collection.as_ref()],
+        bump = bump,
+    )]
+    pub drop: Account<'info, CollectionDrop>,
+
+    #[account(address = system_program::id())]
+    pub system_program: Program<'info, System>,
+
+    pub unpaused: Unpaused<'info>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateDropContext<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [CollectionDrop::SEED, drop.collection.as_ref()],
+        bump = drop.bump,
+        has_one = authority @ ErrorCode::InvalidDropAuthority,
+    )]
+    pub drop: Account<'info, CollectionDrop>,
+
+    pub unpaused: Unpaused<'info>,
+}
+
+// same accounts as GenerateWithMintContext, except that the drop pda is the mint and update
+// authority instead of a signer, and the nft always goes to the buyer
+#[derive(Accounts)]
+pub struct MintFromDropContext<'info> {
+    // buyer; pays the price, the generate fee and rent, and receives the nft
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [CollectionDrop::SEED, drop.collection.as_ref()],
+        bump = drop.bump,
+    )]
+    pub drop: Account<'info, CollectionDrop>,
+
+    // template of the drop's collection, set up by the same authority as the drop
+    #[account(
+        seeds = [CollectionTemplate::SEED, drop.collection.as_ref()],
+        bump = template.bump,
+        constraint = template.collection == drop.collection @ ErrorCode::MetadataCollectionMismatch,
+        constraint = template.authority == drop.authority @ ErrorCode::DropTemplateAuthorityMismatch,
+    )]
+    pub template: Account<'info, CollectionTemplate>,
+
+    /// CHECK: receives the price of the nft, any account set in the drop
+    #[account(
+        mut,
+        constraint = drop_treasury.key() == drop.treasury @ ErrorCode::InvalidTreasury,
+    )]
+    pub drop_treasury: UncheckedAccount<'info>,
+
+    /// CHECK: receives the generate fee, any account set in the config
+    #[account(
+        mut,
+        constraint = treasury.key() == unpaused.config.treasury @ ErrorCode::InvalidTreasury,
+    )]
+    pub treasury: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        payer = payer,
+        mint::decimals = 0,
+        mint::authority = drop,
+        mint::freeze_authority = drop,
+    )]
+    pub mint: Account<'info, Mint>,
+
+    #[account(
+        init,
+        payer = payer,
+        associated_token::mint = mint,
+        associated_token::authority = payer,
+    )]
+    pub token_account: Account<'info, TokenAccount>,
+
+    /// CHECK: derivation is verified against the metaplex metadata program, rest via cpi
+    #[account(
+        mut,
+        constraint = metadata.key() == find_metadata_account(&mint.key()).0 @ ErrorCode::InvalidMetadataKey,
+    )]
+    pub metadata: AccountInfo<'info>,
+
+    /// CHECK: derivation is verified against the metaplex metadata program, rest via cpi
+    #[account(
+        mut,
+        constraint = master_edition.key() == find_master_edition_account(&mint.key()).0 @ ErrorCode::InvalidMasterEditionKey,
+    )]
+    pub master_edition: AccountInfo<'info>,
+
+    /// CHECK: verified via cpi into the metaplex metadata program
+    #[account(address = mpl_token_metadata::id())]
+    pub token_metadata_program: UncheckedAccount<'info>,
+
+    #[account(address = spl_token::id())]
+    pub token_program: Program<'info, Token>,
+
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    #[account(address = system_program::id())]
+    pub system_program: Program<'info, System>,
+
+    pub rent: Sysvar<'info, Rent>,
+
+    pub unpaused: Unpaused<'info>,
+}
+
+#[derive(Accounts)]
+pub struct InitializeContentHashContext<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    // only the key is used, so the account can be created before generate_with_mint creates the
+    // mint
+    /// CHECK: seeds the content hash account
+    pub mint: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = ContentHash::LEN,
+        seeds = [ContentHash::SEED, mint.key().as_ref()],
+        bump,
+    )]
+    pub content_hash: Account<'info, ContentHash>,
+
+    #[account(address = system_program::id())]
+    pub system_program: Program<'info, System>,
+
+    pub unpaused: Unpaused<'info>,
+}
+
+// ==============================================
+// cpi context transformations
+// ==============================================
+
+impl<'info> GenerateContext<'info> {
+    pub fn into_collect_fee_context(&self) -> CpiContext<'_, '_, '_, 'info, CollectFee<'info>> {
+        let cpi_program = self.system_program.to_account_info();
+
+        let cpi_accounts = CollectFee {
+            payer: self.payer.to_account_info(),
+            treasury: self.treasury.to_account_info(),
+            system_program: self.system_program.clone(),
+        };
+
+        CpiContext::new(cpi_program, cpi_accounts)
+    }
+
+    // spl token fee accounts are only required when a fee mint is configured, so they're passed as
+    // remaining accounts: [payer token account, treasury token account]
+    pub fn into_collect_token_fee_context(
+        &self,
+        remaining_accounts: &[AccountInfo<'info>],
+    ) -> Result<CpiContext<'_, '_, '_, 'info, CollectTokenFee<'info>>, ProgramError> {
+        let cpi_program = self.token_program.to_account_info();
+
+        let cpi_accounts = match remaining_accounts {
+            [payer_token_account, treasury_token_account, ..] => CollectTokenFee {
+                payer: self.payer.to_account_info(),
+                treasury: self.treasury.to_account_info(),
+                payer_token_account: payer_token_account.clone(),
+                treasury_token_account: treasury_token_account.clone(),
+                token_program: self.token_program.clone(),
+            },
+            _ => return Err(ProgramError::NotEnoughAccountKeys),
+        };
+
+        Ok(CpiContext::new(cpi_program, cpi_accounts))
+    }
+
+    pub fn into_create_metadata_context(
+        &self,
+    ) -> CpiContext<'_, '_, '_, 'info, CreateMetadata<'info>> {
+        let cpi_program = self.token_metadata_program.to_account_info();
+
+        let cpi_accounts = CreateMetadata {
+            metadata: self.metadata.to_account_info(),
+            mint: self.mint.to_account_info(),
+            mint_authority: self.mint_authority.to_account_info(),
+            payer: self.payer.to_account_info(),
+            update_authority: self.update_authority.to_account_info(),
+            token_metadata_program: self.token_metadata_program.clone(),
+            token_program: self.token_program.clone(),
+            system_program: self.system_program.clone(),
+            rent: self.rent.clone(),
+        };
+
+        CpiContext::new(cpi_program, cpi_accounts)
+    }
+
+    pub fn into_create_master_edition_metadata_context(
+        &self,
+    ) -> CpiContext<'_, '_, '_, 'info, CreateMasterEdition<'info>> {
+        let cpi_program = self.token_metadata_program.to_account_info();
+
+        // is mint_authority & update_authority = payer / minter?
+        // yes — https://github.com/exiled-apes/candy-machine-mint/blob/main/src/candy-machine.ts#L268-L269
+        let cpi_accounts = CreateMasterEdition {
+            payer: self.payer.to_account_info(),
+            metadata: self.metadata.to_account_info(),
+            master_edition: self.master_edition.to_account_info(),
+            mint: self.mint.to_account_info(),
+            mint_authority: self.mint_authority.to_account_info(),
+            update_authority: self.update_authority.to_account_info(),
+            token_metadata_program: self.token_metadata_program.clone(),
+            token_program: self.token_program.clone(),
+            system_program: self.system_program.clone(),
+            rent: self.rent.clone(),
+        };
+
+        CpiContext::new(cpi_program, cpi_accounts)
+    }
+
+    pub fn into_update_metadata_context(
+        &self,
+    ) -> CpiContext<'_, '_, '_, 'info, UpdateMetadata<'info>> {
+        let cpi_program = self.token_metadata_program.to_account_info();
+
+        let cpi_accounts = UpdateMetadata {
+            metadata: self.metadata.to_account_info(),
+            update_authority: self.update_authority.to_account_info(),
+            token_metadata_program: self.token_metadata_program.to_account_info(),
+        };
+
+        CpiContext::new(cpi_program, cpi_accounts)
+    }
+}
+
+impl<'info> GenerateWithMintContext<'info> {
+    pub fn into_mint_to_context(&self) -> CpiContext<'_, '_, '_, 'info, MintTo<'info>> {
+        let cpi_program = self.token_program.to_account_info();
+
+        let cpi_accounts = MintTo {
+            mint: self.mint.to_account_info(),
+            to: self.token_account.to_account_info(),
+            authority: self.mint_authority.to_account_info(),
+        };
+
+        CpiContext::new(cpi_program, cpi_accounts)
+    }
+
+    // the mint only holds its token after mint_to, so the generate accounts can't be nested and
+    // validated up front. once the token is minted the same accounts are handed to the generate
+    // pipeline, which reuses the GenerateContext cpi builders.
+    pub fn into_generate_context(&self) -> Result<GenerateContext<'info>, ProgramError> {
+        Ok(GenerateContext {
+            payer: self.payer.clone(),
+            mint_authority: self.mint_authority.clone(),
+            update_authority: self.update_authority.clone(),
+            treasury: self.treasury.clone(),
+            // reload so that the supply reflects the token minted in this instruction
+            mint: Account::try_from(&self.mint.to_account_info())?,
+            metadata: self.metadata.clone(),
+            master_edition: self.master_edition.clone(),
+            token_metadata_program: self.token_metadata_program.clone(),
+            token_program: self.token_program.clone(),
+            system_program: self.system_program.clone(),
+            rent: self.rent.clone(),
+            unpaused: self.unpaused.clone(),
+        })
+    }
+}
+
+impl<'info> PrintEditionContext<'info> {
+    pub fn into_print_edition_context(&self) -> CpiContext<'_, '_, '_, 'info, PrintEdition<'info>> {
+        let cpi_program = self.token_metadata_program.to_account_info();
+
+        let cpi_accounts = PrintEdition {
+            payer: self.payer.to_account_info(),
+            new_metadata: self.new_metadata.to_account_info(),
+            new_edition: self.new_edition.to_account_info(),
+            new_mint: self.new_mint.to_account_info(),
+            new_mint_authority: self.new_mint_authority.to_account_info(),
+            new_metadata_update_authority: self.new_metadata_update_authority.to_account_info(),
+            edition_marker: self.edition_marker.to_account_info(),
+            master_edition: self.master_edition.to_account_info(),
+            master_metadata: self.master_metadata.to_account_info(),
+            master_mint: self.master_mint.to_account_info(),
+            token_account_owner: self.token_account_owner.to_account_info(),
+            token_account: self.token_account.to_account_info(),
+            token_metadata_program: self.token_metadata_program.clone(),
+            token_program: self.token_program.clone(),
+            system_program: self.system_program.clone(),
+            rent: self.rent.clone(),
+        };
+
+        CpiContext::new(cpi_program, cpi_accounts)
+    }
+}
+
+impl<'info> SignMetadataContext<'info> {
+    pub fn into_sign_metadata_context(&self) -> CpiContext<'_, '_, '_, 'info, SignMetadata<'info>> {
+        let cpi_program = self.token_metadata_program.to_account_info();
+
+        let cpi_accounts = SignMetadata {
+            metadata: self.metadata.to_account_info(),
+            creator: self.creator.to_account_info(),
+            token_metadata_program: self.token_metadata_program.to_account_info(),
+        };
+
+        CpiContext::new(cpi_program, cpi_accounts)
+    }
+}
+
+impl<'info> VerifyCollectionContext<'info> {
+    pub fn into_verify_collection_context(
+        &self,
+    ) -> CpiContext<'_, '_, '_, 'info, VerifyCollection<'info>> {
+        let cpi_program = self.token_metadata_program.to_account_info();
+
+        let cpi_accounts = VerifyCollection {
+            metadata: self.metadata.to_account_info(),
+            collection_authority: self.collection_authority.to_account_info(),
+            payer: self.payer.to_account_info(),
+            collection_mint: self.collection_mint.to_account_info(),
+            collection_metadata: self.collection_metadata.to_account_info(),
+            collection_master_edition: self.collection_master_edition.to_account_info(),
+            token_metadata_program: self.token_metadata_program.to_account_info(),
+        };
+
+        CpiContext::new(cpi_program, cpi_accounts)
+    }
+}
+
+impl<'info> SetAndVerifyCollectionContext<'info> {
+    pub fn into_set_and_verify_collection_context(
+        &self,
+    ) -> CpiContext<'_, '_, '_, 'info, SetAndVerifyCollection<'info>> {
+        let cpi_program = self.token_metadata_program.to_account_info();
+
+        let cpi_accounts = SetAndVerifyCollection {
+            verify: VerifyCollection {
+                metadata: self.metadata.to_account_info(),
+                collection_authority: self.collection_authority.to_account_info(),
+                payer: self.payer.to_account_info(),
+                collection_mint: self.collection_mint.to_account_info(),
+                collection_metadata: self.collection_metadata.to_account_info(),
+                collection_master_edition: self.collection_master_edition.to_account_info(),
+                token_metadata_program: self.token_metadata_program.to_account_info(),
+            },
+            update_authority: self.update_authority.to_account_info(),
+        };
+
+        CpiContext::new(cpi_program, cpi_accounts)
+    }
+}
+
+impl<'info> BurnNftContext<'info> {
+    pub fn into_burn_nft_context(&self) -> CpiContext<'_, '_, '_, 'info, BurnNft<'info>> {
+        let cpi_program = self.token_metadata_program.to_account_info();
+
+        let cpi_accounts = BurnNft {
+            owner: self.owner.to_account_info(),
+            metadata: self.metadata.to_account_info(),
+            mint: self.mint.to_account_info(),
+            token_account: self.token_account.to_account_info(),
+            master_edition: self.master_edition.to_account_info(),
+            receiver: self.receiver.to_account_info(),
+            token_metadata_program: self.token_metadata_program.clone(),
+            token_program: self.token_program.clone(),
+            system_program: self.system_program.clone(),
+        };
+
+        CpiContext::new(cpi_program, cpi_accounts)
+    }
+}
+
+impl<'info> UtilizeContext<'info> {
+    pub fn into_utilize_context(&self) -> CpiContext<'_, '_, '_, 'info, Utilize<'info>> {
+        let cpi_program = self.token_metadata_program.to_account_info();
+
+        let cpi_accounts = Utilize {
+            metadata: self.metadata.to_account_info(),
+            token_account: self.token_account.to_account_info(),
+            mint: self.mint.to_account_info(),
+            use_authority: self.use_authority.to_account_info(),
+            owner: self.owner.to_account_info(),
+            use_authority_record: self.use_authority_record.to_account_info(),
+            burner: self.burner.to_account_info(),
+            token_metadata_program: self.token_metadata_program.clone(),
+            token_program: self.token_program.clone(),
+            associated_token_program: self.associated_token_program.to_account_info(),
+            system_program: self.system_program.clone(),
+            rent: self.rent.clone(),
+        };
+
+        CpiContext::new(cpi_program, cpi_accounts)
+    }
+}
+
+impl<'info> UseAuthorityContext<'info> {
+    pub fn into_use_authority_context(&self) -> CpiContext<'_, '_, '_, 'info, UseAuthority<'info>> {
+        let cpi_program = self.token_metadata_program.to_account_info();
+
+        let cpi_accounts = UseAuthority {
+            use_authority_record: self.use_authority_record.to_account_info(),
+            owner: self.owner.to_account_info(),
+            payer: self.payer.to_account_info(),
+            user: self.user.to_account_info(),
+            owner_token_account: self.owner_token_account.to_account_info(),
+            metadata: self.metadata.to_account_info(),
+            mint: self.mint.to_account_info(),
+            burner: self.burner.to_account_info(),
+            token_metadata_program: self.token_metadata_program.clone(),
+            token_program: self.token_program.clone(),
+            system_program: self.system_program.clone(),
+            rent: self.rent.clone(),
+        };
+
+        CpiContext::new(cpi_program, cpi_accounts)
+    }
+}
+
+impl<'info> UpdateMetadataContext<'info> {
+    pub fn into_update_metadata_context(
+        &self,
+    ) -> CpiContext<'_, '_, '_, 'info, UpdateMetadata<'info>> {
+        let cpi_program = self.token_metadata_program.to_account_info();
+
+        let cpi_accounts = UpdateMetadata {
+            metadata: self.metadata.to_account_info(),
+            update_authority: self.update_authority.to_account_info(),
+            token_metadata_program: self.token_metadata_program.to_account_info(),
+        };
 
-    pub mint_authority: Signer<'info>,
+        CpiContext::new(cpi_program, cpi_accounts)
+    }
+}
 
-    pub update_authority: Signer<'info>,
+impl<'info> ProposeUpdateAuthorityContext<'info> {
+    pub fn into_update_metadata_context(
+        &self,
+    ) -> CpiContext<'_, '_, '_, 'info, UpdateMetadata<'info>> {
+        let cpi_program = self.token_metadata_program.to_account_info();
 
-    #[account(
-        mut,
-        constraint = mint.decimals == 0,
-        constraint = mint.supply == 1,
-        constraint = mint.freeze_authority.unwrap() == update_authority.key(),
-        constraint = mint.mint_authority.unwrap() == update_authority.key(),
-    )]
-    pub mint: Account<'info, Mint>,
+        let cpi_accounts = UpdateMetadata {
+            metadata: self.metadata.to_account_info(),
+            update_authority: self.update_authority.to_account_info(),
+            token_metadata_program: self.token_metadata_program.to_account_info(),
+        };
 
-    /// CHECK: verified via cpi into the metaplex metadata program
-    #[account(mut)]
-    pub metadata: AccountInfo<'info>,
+        CpiContext::new(cpi_program, cpi_accounts)
+    }
+}
 
-    /// CHECK: verified via cpi into the metaplex metadata program
-    #[account(mut)]
-    pub master_edition: AccountInfo<'info>,
+impl<'info> AcceptUpdateAuthorityContext<'info> {
+    // caller is expected to attach the pending transfer's signer seeds
+    pub fn into_update_metadata_context(
+        &self,
+    ) -> CpiContext<'_, '_, '_, 'info, UpdateMetadata<'info>> {
+        let cpi_program = self.token_metadata_program.to_account_info();
 
-    /// CHECK: verified via cpi into the metaplex metadata program
-    #[account(address = mpl_token_metadata::id())]
-    pub token_metadata_program: UncheckedAccount<'info>,
+        let cpi_accounts = UpdateMetadata {
+            metadata: self.metadata.to_account_info(),
+            update_authority: self.pending_update_authority.to_account_info(),
+            token_metadata_program: self.token_metadata_program.to_account_info(),
+        };
 
-    #[account(address = spl_token::id())]
-    pub token_program: Program<'info, Token>,
+        CpiContext::new(cpi_program, cpi_accounts)
+    }
+}
 
-    #[account(address = system_program::id())]
-    pub system_program: Program<'info, System>,
+impl<'info> CancelUpdateAuthorityTransferContext<'info> {
+    // caller is expected to attach the pending transfer's signer seeds
+    pub fn into_update_metadata_context(
+        &self,
+    ) -> CpiContext<'_, '_, '_, 'info, UpdateMetadata<'info>> {
+        let cpi_program = self.token_metadata_program.to_account_info();
 
-    pub rent: Sysvar<'info, Rent>,
+        let cpi_accounts = UpdateMetadata {
+            metadata: self.metadata.to_account_info(),
+            update_authority: self.pending_update_authority.to_account_info(),
+            token_metadata_program: self.token_metadata_program.to_account_info(),
+        };
+
+        CpiContext::new(cpi_program, cpi_accounts)
+    }
 }
 
-#[derive(Accounts)]
-pub struct SignMetadataContext<'info> {
-    pub creator: Signer<'info>,
+impl<'info> VaultUpdateMetadataContext<'info> {
+    // caller is expected to attach the vault's signer seeds
+    pub fn into_update_metadata_context(
+        &self,
+    ) -> CpiContext<'_, '_, '_, 'info, UpdateMetadata<'info>> {
+        let cpi_program = self.token_metadata_program.to_account_info();
 
-    /// no need to check seeds here because account will be verified via CPI call into metaplex metadata contract
-    #[account(mut)]
-    pub metadata: Account<'info, MetadataWrapper>,
+        let cpi_accounts = UpdateMetadata {
+            metadata: self.metadata.to_account_info(),
+            update_authority: self.vault.to_account_info(),
+            token_metadata_program: self.token_metadata_program.to_account_info(),
+        };
 
-    /// CHECK: verified via cpi into the metaplex metadata program
-    #[account(address = spl_token_metadata::id())]
-    pub token_metadata_program: AccountInfo<'info>,
+        CpiContext::new(cpi_program, cpi_accounts)
+    }
 }
 
-#[derive(Accounts)]
-pub struct UpdateMetadataContext<'info> {
-    #[account(mut)]
-    pub update_authority: Signer<'info>,
+impl<'info> VaultVerifyCollectionContext<'info> {
+    // caller is expected to attach the vault's signer seeds
+    pub fn into_verify_collection_context(
+        &self,
+    ) -> CpiContext<'_, '_, '_, 'info, VerifyCollection<'info>> {
+        let cpi_program = self.token_metadata_program.to_account_info();
 
-    /// no need to check seeds here because account will be verified via CPI call into metaplex metadata contract
-    #[account(mut)]
-    pub metadata: Account<'info, MetadataWrapper>,
+        let cpi_accounts = VerifyCollection {
+            metadata: self.metadata.to_account_info(),
+            collection_authority: self.vault.to_account_info(),
+            payer: self.payer.to_account_info(),
+            collection_mint: self.collection_mint.to_account_info(),
+            collection_metadata: self.collection_metadata.to_account_info(),
+            collection_master_edition: self.collection_master_edition.to_account_info(),
+            token_metadata_program: self.token_metadata_program.to_account_info(),
+        };
 
-    /// CHECK: verified via cpi into the metaplex metadata program
-    #[account(address = spl_token_metadata::id())]
-    pub token_metadata_program: AccountInfo<'info>,
+        CpiContext::new(cpi_program, cpi_accounts)
+    }
 }
 
-// ==============================================
-// cpi context transformations
-// ==============================================
+impl<'info> ExecuteMultisigUpdateContext<'info> {
+    // caller is expected to attach the multisig's signer seeds
+    pub fn into_update_metadata_context(
+        &self,
+    ) -> CpiContext<'_, '_, '_, 'info, UpdateMetadata<'info>> {
+        let cpi_program = self.token_metadata_program.to_account_info();
+
+        let cpi_accounts = UpdateMetadata {
+            metadata: self.metadata.to_account_info(),
+            update_authority: self.multisig.to_account_info(),
+            token_metadata_program: self.token_metadata_program.to_account_info(),
+        };
+
+        CpiContext::new(cpi_program, cpi_accounts)
+    }
+}
+
+impl<'info> ExecuteMetadataUpdateContext<'info> {
+    // caller is expected to attach the timelock's signer seeds
+    pub fn into_update_metadata_context(
+        &self,
+    ) -> CpiContext<'_, '_, '_, 'info, UpdateMetadata<'info>> {
+        let cpi_program = self.token_metadata_program.to_account_info();
+
+        let cpi_accounts = UpdateMetadata {
+            metadata: self.metadata.to_account_info(),
+            update_authority: self.timelock.to_account_info(),
+            token_metadata_program: self.token_metadata_program.to_account_info(),
+        };
+
+        CpiContext::new(cpi_program, cpi_accounts)
+    }
+}
+
+impl<'info> RevealContext<'info> {
+    // caller is expected to attach the commitment's signer seeds
+    pub fn into_update_metadata_context(
+        &self,
+    ) -> CpiContext<'_, '_, '_, 'info, UpdateMetadata<'info>> {
+        let cpi_program = self.token_metadata_program.to_account_info();
+
+        let cpi_accounts = UpdateMetadata {
+            metadata: self.metadata.to_account_info(),
+            update_authority: self.commitment.to_account_info(),
+            token_metadata_program: self.token_metadata_program.to_account_info(),
+        };
+
+        CpiContext::new(cpi_program, cpi_accounts)
+    }
+}
+
+impl<'info> ReleaseRevealAuthorityContext<'info> {
+    // caller is expected to attach the commitment's signer seeds
+    pub fn into_update_metadata_context(
+        &self,
+    ) -> CpiContext<'_, '_, '_, 'info, UpdateMetadata<'info>> {
+        let cpi_program = self.token_metadata_program.to_account_info();
+
+        let cpi_accounts = UpdateMetadata {
+            metadata: self.metadata.to_account_info(),
+            update_authority: self.commitment.to_account_info(),
+            token_metadata_program: self.token_metadata_program.to_account_info(),
+        };
+
+        CpiContext::new(cpi_program, cpi_accounts)
+    }
+}
+
+// every builder is expected to get the drop's signer seeds attached by the caller
+impl<'info> GenerateAllowlistedContext<'info> {
+    pub fn into_collect_fee_context(&self) -> CpiContext<'_, '_, '_, 'info, CollectFee<'info>> {
+        let cpi_program = self.system_program.to_account_info();
+
+        let cpi_accounts = CollectFee {
+            payer: self.payer.to_account_info(),
+            treasury: self.treasury.to_account_info(),
+            system_program: self.system_program.clone(),
+        };
+
+        CpiContext::new(cpi_program, cpi_accounts)
+    }
+
+    // same remaining accounts as GenerateContext::into_collect_token_fee_context
+    pub fn into_collect_token_fee_context(
+        &self,
+        remaining_accounts: &[AccountInfo<'info>],
+    ) -> Result<CpiContext<'_, '_, '_, 'info, CollectTokenFee<'info>>, ProgramError> {
+        let cpi_program = self.token_program.to_account_info();
+
+        let cpi_accounts = match remaining_accounts {
+            [payer_token_account, treasury_token_account, ..] => CollectTokenFee {
+                payer: self.payer.to_account_info(),
+                treasury: self.treasury.to_account_info(),
+                payer_token_account: payer_token_account.clone(),
+                treasury_token_account: treasury_token_account.clone(),
+                token_program: self.token_program.clone(),
+            },
+            _ => return Err(ProgramError::NotEnoughAccountKeys),
+        };
+
+        Ok(CpiContext::new(cpi_program, cpi_accounts))
+    }
+
+    pub fn into_mint_to_context(&self) -> CpiContext<'_, '_, '_, 'info, MintTo<'info>> {
+        let cpi_program = self.token_program.to_account_info();
+
+        let cpi_accounts = MintTo {
+            mint: self.mint.to_account_info(),
+            to: self.token_account.to_account_info(),
+            authority: self.allowlist.to_account_info(),
+        };
+
+        CpiContext::new(cpi_program, cpi_accounts)
+    }
 
-impl<'info> GenerateContext<'info> {
     pub fn into_create_metadata_context(
         &self,
     ) -> CpiContext<'_, '_, '_, 'info, CreateMetadata<'info>> {
@@ -89,9 +2020,9 @@ impl<'info> GenerateContext<'info> {
         let cpi_accounts = CreateMetadata {
             metadata: self.metadata.to_account_info(),
             mint: self.mint.to_account_info(),
-            mint_authority: self.mint_authority.to_account_info(),
+            mint_authority: self.allowlist.to_account_info(),
             payer: self.payer.to_account_info(),
-            update_authority: self.update_authority.to_account_info(),
+            update_authority: self.allowlist.to_account_info(),
             token_metadata_program: self.token_metadata_program.clone(),
             token_program: self.token_program.clone(),
             system_program: self.system_program.clone(),
@@ -106,15 +2037,13 @@ impl<'info> GenerateContext<'info> {
     ) -> CpiContext<'_, '_, '_, 'info, CreateMasterEdition<'info>> {
         let cpi_program = self.token_metadata_program.to_account_info();
 
-        // is mint_authority & update_authority = payer / minter?
-        // yes — https://github.com/exiled-apes/candy-machine-mint/blob/main/src/candy-machine.ts#L268-L269
         let cpi_accounts = CreateMasterEdition {
             payer: self.payer.to_account_info(),
             metadata: self.metadata.to_account_info(),
             master_edition: self.master_edition.to_account_info(),
             mint: self.mint.to_account_info(),
-            mint_authority: self.mint_authority.to_account_info(),
-            update_authority: self.update_authority.to_account_info(),
+            mint_authority: self.allowlist.to_account_info(),
+            update_authority: self.allowlist.to_account_info(),
             token_metadata_program: self.token_metadata_program.clone(),
             token_program: self.token_program.clone(),
             system_program: self.system_program.clone(),
@@ -131,7 +2060,7 @@ impl<'info> GenerateContext<'info> {
 
         let cpi_accounts = UpdateMetadata {
             metadata: self.metadata.to_account_info(),
-            update_authority: self.update_authority.to_account_info(),
+            update_authority: self.allowlist.to_account_info(),
             token_metadata_program: self.token_metadata_program.to_account_info(),
         };
 
@@ -139,21 +2068,105 @@ impl<'info> GenerateContext<'info> {
     }
 }
 
-impl<'info> SignMetadataContext<'info> {
-    pub fn into_sign_metadata_context(&self) -> CpiContext<'_, '_, '_, 'info, SignMetadata<'info>> {
+impl<'info> MintFromDropContext<'info> {
+    pub fn into_pay_drop_context(&self) -> CpiContext<'_, '_, '_, 'info, CollectFee<'info>> {
+        let cpi_program = self.system_program.to_account_info();
+
+        let cpi_accounts = CollectFee {
+            payer: self.payer.to_account_info(),
+            treasury: self.drop_treasury.to_account_info(),
+            system_program: self.system_program.clone(),
+        };
+
+        CpiContext::new(cpi_program, cpi_accounts)
+    }
+
+    pub fn into_collect_fee_context(&self) -> CpiContext<'_, '_, '_, 'info, CollectFee<'info>> {
+        let cpi_program = self.system_program.to_account_info();
+
+        let cpi_accounts = CollectFee {
+            payer: self.payer.to_account_info(),
+            treasury: self.treasury.to_account_info(),
+            system_program: self.system_program.clone(),
+        };
+
+        CpiContext::new(cpi_program, cpi_accounts)
+    }
+
+    // same remaining accounts as GenerateContext::into_collect_token_fee_context
+    pub fn into_collect_token_fee_context(
+        &self,
+        remaining_accounts: &[AccountInfo<'info>],
+    ) -> Result<CpiContext<'_, '_, '_, 'info, CollectTokenFee<'info>>, ProgramError> {
+        let cpi_program = self.token_program.to_account_info();
+
+        let cpi_accounts = match remaining_accounts {
+            [payer_token_account, treasury_token_account, ..] => CollectTokenFee {
+                payer: self.payer.to_account_info(),
+                treasury: self.treasury.to_account_info(),
+                payer_token_account: payer_token_account.clone(),
+                treasury_token_account: treasury_token_account.clone(),
+                token_program: self.token_program.clone(),
+            },
+            _ => return Err(ProgramError::NotEnoughAccountKeys),
+        };
+
+        Ok(CpiContext::new(cpi_program, cpi_accounts))
+    }
+
+    pub fn into_mint_to_context(&self) -> CpiContext<'_, '_, '_, 'info, MintTo<'info>> {
+        let cpi_program = self.token_program.to_account_info();
+
+        let cpi_accounts = MintTo {
+            mint: self.mint.to_account_info(),
+            to: self.token_account.to_account_info(),
+            authority: self.drop.to_account_info(),
+        };
+
+        CpiContext::new(cpi_program, cpi_accounts)
+    }
+
+    pub fn into_create_metadata_context(
+        &self,
+    ) -> CpiContext<'_, '_, '_, 'info, CreateMetadata<'info>> {
         let cpi_program = self.token_metadata_program.to_account_info();
 
-        let cpi_accounts = SignMetadata {
+        let cpi_accounts = CreateMetadata {
             metadata: self.metadata.to_account_info(),
-            creator: self.creator.to_account_info(),
-            token_metadata_program: self.token_metadata_program.to_account_info(),
+            mint: self.mint.to_account_info(),
+            mint_authority: self.drop.to_account_info(),
+            payer: self.payer.to_account_info(),
+            update_authority: self.drop.to_account_info(),
+            token_metadata_program: self.token_metadata_program.clone(),
+            token_program: self.token_program.clone(),
+            system_program: self.system_program.clone(),
+            rent: self.rent.clone(),
+        };
+
+        CpiContext::new(cpi_program, cpi_accounts)
+    }
+
+    pub fn into_create_master_edition_metadata_context(
+        &self,
+    ) -> CpiContext<'_, '_, '_, 'info, CreateMasterEdition<'info>> {
+        let cpi_program = self.token_metadata_program.to_account_info();
+
+        let cpi_accounts = CreateMasterEdition {
+            payer: self.payer.to_account_info(),
+            metadata: self.metadata.to_account_info(),
+            master_edition: self.master_edition.to_account_info(),
+            mint: self.mint.to_account_info(),
+            mint_authority: self.drop.to_account_info(),
+            update_authority: self.drop.to_account_info(),
+            token_metadata_program: self.token_metadata_program.clone(),
+            token_program: self.token_program.clone(),
+            system_program: self.system_program.clone(),
+            rent: self.rent.clone(),
         };
 
         CpiContext::new(cpi_program, cpi_accounts)
     }
-}
 
-impl<'info> UpdateMetadataContext<'info> {
     pub fn into_update_metadata_context(
         &self,
     ) -> CpiContext<'_, '_, '_, 'info, UpdateMetadata<'info>> {
@@ -161,7 +2174,7 @@ impl<'info> UpdateMetadataContext<'info> {
 
         let cpi_accounts = UpdateMetadata {
             metadata: self.metadata.to_account_info(),
-            update_authority: self.update_authority.to_account_info(),
+            update_authority: self.drop.to_account_info(),
             token_metadata_program: self.token_metadata_program.to_account_info(),
         };
 
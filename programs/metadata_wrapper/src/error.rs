@@ -0,0 +1,217 @@
+use anchor_lang::prelude::*;
+
+// a failing cpi aborts the whole transaction, so errors returned by the metaplex metadata program
+// can't be caught and re-mapped after the fact. instead, the conditions behind its most common
+// errors are checked before invoking it, and surfaced with the codes below.
+#[error]
+pub enum ErrorCode {
+    #[msg("Master edition has already printed its max supply")]
+    MaxSupplyReached,
+
+    #[msg("Edition marker account does not match the next edition number")]
+    InvalidEditionMarker,
+
+    #[msg("Edition account does not match the new mint")]
+    InvalidEdition,
+
+    #[msg("Metadata does not have any uses")]
+    MetadataNotUsable,
+
+    #[msg("Not enough uses remaining")]
+    NotEnoughUses,
+
+    #[msg("Single use metadata can only be used once per instruction")]
+    InvalidNumberOfUses,
+
+    #[msg("Use authority record does not match the mint and use authority")]
+    InvalidUseAuthorityRecord,
+
+    #[msg("Burner account does not match the metadata program burner")]
+    InvalidBurner,
+
+    #[msg("Mint must have a freeze authority")]
+    MissingFreezeAuthority,
+
+    #[msg("Mint must have a mint authority")]
+    MissingMintAuthority,
+
+    #[msg("Freeze authority of the mint does not match the update authority")]
+    InvalidFreezeAuthority,
+
+    #[msg("Mint authority of the mint does not match the update authority")]
+    InvalidMintAuthority,
+
+    #[msg("Mint must have 0 decimals and a supply of 1")]
+    MintNotNft,
+
+    #[msg("Metadata account already exists for this mint")]
+    MetadataAlreadyExists,
+
+    #[msg("Master edition account already exists for this mint")]
+    MasterEditionAlreadyExists,
+
+    #[msg("Metadata does not belong to the given mint")]
+    MetadataMintMismatch,
+
+    #[msg("Token account does not hold the expected token for the given owner")]
+    InvalidTokenAccount,
+
+    #[msg("Creator shares must add up to 100")]
+    InvalidCreatorShares,
+
+    #[msg("Update authority does not match the metadata update authority")]
+    UpdateAuthorityIncorrect,
+
+    #[msg("Metadata is immutable")]
+    MetadataImmutable,
+
+    #[msg("Signer is not a creator of the metadata")]
+    CreatorNotFound,
+
+    #[msg("Name is longer than the metaplex limit")]
+    NameTooLong,
+
+    #[msg("Symbol is longer than the metaplex limit")]
+    SymbolTooLong,
+
+    #[msg("Uri is longer than the metaplex limit")]
+    UriTooLong,
+
+    #[msg("Seller fee basis points can't be more than 10000")]
+    InvalidSellerFeeBasisPoints,
+
+    #[msg("Metadata can't have more than 5 creators")]
+    TooManyCreators,
+
+    #[msg("Creator addresses must be unique")]
+    DuplicateCreator,
+
+    #[msg("Remaining uses can't be more than total uses")]
+    InvalidUses,
+
+    #[msg("Metadata account must match seeds ['metadata', metadata program id, mint]")]
+    InvalidMetadataKey,
+
+    #[msg("Master edition account must match seeds ['metadata', metadata program id, mint, 'edition']")]
+    InvalidMasterEditionKey,
+
+    #[msg("Signer is not the proposed update authority")]
+    InvalidProposedUpdateAuthority,
+
+    #[msg("Signer is not the authority of the vault")]
+    InvalidVaultAuthority,
+
+    #[msg("Multisig needs between 1 and 10 unique owners")]
+    InvalidMultisigOwners,
+
+    #[msg("Multisig threshold must be between 1 and the number of owners")]
+    InvalidMultisigThreshold,
+
+    #[msg("Signer is not an owner of the multisig")]
+    NotMultisigOwner,
+
+    #[msg("Owner already approved this proposal")]
+    ProposalAlreadyApproved,
+
+    #[msg("Proposal does not have enough approvals yet")]
+    ProposalThresholdNotReached,
+
+    #[msg("Signer is not the authority of the timelock")]
+    InvalidTimelockAuthority,
+
+    #[msg("Timelock delay must be positive")]
+    InvalidTimelockDelay,
+
+    #[msg("Metadata does not belong to the collection")]
+    MetadataCollectionMismatch,
+
+    #[msg("Queued update cannot be executed yet")]
+    TimelockNotExpired,
+
+    #[msg("Timelock delay can only be increased")]
+    TimelockDelayDecreased,
+
+    #[msg("Metadata history account must be writable")]
+    HistoryNotWritable,
+
+    #[msg("Program is paused")]
+    ProgramPaused,
+
+    #[msg("Signer is not the admin of the program")]
+    InvalidAdmin,
+
+    #[msg("Program data account does not belong to this program")]
+    InvalidProgramData,
+
+    #[msg("Treasury does not match the config")]
+    InvalidTreasury,
+
+    #[msg("Too many fee waivers")]
+    TooManyFeeWaivers,
+
+    #[msg("Fee token account does not match the fee mint, payer or treasury")]
+    InvalidFeeTokenAccount,
+
+    #[msg("Config has no fee mint to pay the generate fee in")]
+    FeeMintNotSet,
+
+    #[msg("Signer is not the authority of the template")]
+    InvalidTemplateAuthority,
+
+    #[msg("Signer is not the authority of the counter")]
+    InvalidCounterAuthority,
+
+    #[msg("Collection has already generated its max supply")]
+    CollectionMaxSupplyReached,
+
+    #[msg("Reveal time has not been reached yet")]
+    RevealNotStarted,
+
+    #[msg("Uri does not match the reveal commitment")]
+    InvalidRevealProof,
+
+    #[msg("Signer is not the authority of the reveal commitment")]
+    InvalidRevealAuthority,
+
+    #[msg("Metadata uri is not the committed one yet")]
+    MetadataNotRevealed,
+
+    #[msg("Payer is not on the allowlist")]
+    NotAllowlisted,
+
+    #[msg("Payer has already minted its allowlist limit")]
+    AllowlistLimitReached,
+
+    #[msg("Signer is not the authority of the allowlist")]
+    InvalidAllowlistAuthority,
+
+    #[msg("Drop must go live before it ends")]
+    InvalidDropTimes,
+
+    #[msg("Drop has not started yet")]
+    DropNotStarted,
+
+    #[msg("Drop has ended")]
+    DropEnded,
+
+    #[msg("Drop has sold out")]
+    DropSoldOut,
+
+    #[msg("Signer is not the authority of the drop")]
+    InvalidDropAuthority,
+
+    #[msg("Drop items need a master edition; the template supply can't be none")]
+    DropRequiresMasterEdition,
+
+    #[msg("Template was not set up by the authority of the drop")]
+    DropTemplateAuthorityMismatch,
+
+    #[msg("Content hash account of the mint must be passed as a remaining account")]
+    ContentHashAccountMissing,
+
+    #[msg("Content hash account must be writable")]
+    ContentHashNotWritable,
+
+    #[msg("Allowlisted nfts need a master edition; the supply can't be none")]
+    AllowlistRequiresMasterEdition,
+}
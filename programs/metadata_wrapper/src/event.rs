@@ -0,0 +1,167 @@
+use {
+    crate::{DataV2, MasterEditionSupply, QueuedMetadataChange},
+    anchor_lang::prelude::*,
+};
+
+#[event]
+pub struct MetadataUsed {
+    pub mint: Pubkey,
+    pub use_authority: Pubkey,
+    pub number_of_uses: u64,
+    pub remaining: u64,
+}
+
+#[event]
+pub struct MetadataGenerated {
+    pub mint: Pubkey,
+    pub metadata: Pubkey,
+    pub payer: Pubkey,
+    pub name: String,
+    pub symbol: String,
+    pub uri: String,
+    pub supply: MasterEditionSupply,
+    pub update_authority: Pubkey,
+    pub content_hash: Option<[u8; 32]>,
+}
+
+#[event]
+pub struct MetadataSigned {
+    pub mint: Pubkey,
+    pub metadata: Pubkey,
+    pub creator: Pubkey,
+}
+
+#[event]
+pub struct MetadataUriUpdated {
+    pub mint: Pubkey,
+    pub metadata: Pubkey,
+    pub update_authority: Pubkey,
+    pub old_uri: String,
+    pub new_uri: String,
+    pub content_hash: Option<[u8; 32]>,
+}
+
+#[event]
+pub struct MetadataUpdated {
+    pub mint: Pubkey,
+    pub metadata: Pubkey,
+    pub update_authority: Pubkey,
+    pub old_data: DataV2,
+    pub new_data: DataV2,
+    pub content_hash: Option<[u8; 32]>,
+}
+
+#[event]
+pub struct UpdateAuthorityProposed {
+    pub mint: Pubkey,
+    pub metadata: Pubkey,
+    pub update_authority: Pubkey,
+    pub proposed_update_authority: Pubkey,
+}
+
+#[event]
+pub struct UpdateAuthorityAccepted {
+    pub mint: Pubkey,
+    pub metadata: Pubkey,
+    pub old_update_authority: Pubkey,
+    pub new_update_authority: Pubkey,
+}
+
+#[event]
+pub struct UpdateAuthorityTransferCancelled {
+    pub metadata: Pubkey,
+    pub update_authority: Pubkey,
+    pub proposed_update_authority: Pubkey,
+}
+
+#[event]
+pub struct VaultAuthorityUpdated {
+    pub vault: Pubkey,
+    pub collection: Pubkey,
+    pub old_authority: Pubkey,
+    pub new_authority: Pubkey,
+}
+
+#[event]
+pub struct MultisigUpdateProposed {
+    pub multisig: Pubkey,
+    pub proposal: Pubkey,
+    pub metadata: Pubkey,
+    pub proposer: Pubkey,
+}
+
+#[event]
+pub struct MultisigUpdateApproved {
+    pub multisig: Pubkey,
+    pub proposal: Pubkey,
+    pub owner: Pubkey,
+    pub approvals: u8,
+    pub threshold: u8,
+}
+
+#[event]
+pub struct MultisigUpdateExecuted {
+    pub multisig: Pubkey,
+    pub proposal: Pubkey,
+    pub metadata: Pubkey,
+}
+
+#[event]
+pub struct TimelockDelayUpdated {
+    pub timelock: Pubkey,
+    pub collection: Pubkey,
+    pub old_delay: i64,
+    pub new_delay: i64,
+}
+
+#[event]
+pub struct MetadataUpdateQueued {
+    pub mint: Pubkey,
+    pub metadata: Pubkey,
+    pub queued_update: Pubkey,
+    pub change: QueuedMetadataChange,
+    pub executable_at: i64,
+}
+
+#[event]
+pub struct MetadataUpdateCancelled {
+    pub metadata: Pubkey,
+    pub queued_update: Pubkey,
+}
+
+#[event]
+pub struct ConfigUpdated {
+    pub admin: Pubkey,
+    pub paused: bool,
+    pub treasury: Pubkey,
+    pub generate_fee_lamports: u64,
+    pub fee_mint: Option<Pubkey>,
+    pub generate_fee_tokens: u64,
+    pub fee_waivers: Vec<Pubkey>,
+}
+
+#[event]
+pub struct FeeCollected {
+    pub mint: Pubkey,
+    pub payer: Pubkey,
+    pub treasury: Pubkey,
+    pub lamports: u64,
+}
+
+#[event]
+pub struct TokenFeeCollected {
+    pub mint: Pubkey,
+    pub payer: Pubkey,
+    pub treasury: Pubkey,
+    pub fee_mint: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct DropMinted {
+    pub drop: Pubkey,
+    pub mint: Pubkey,
+    pub buyer: Pubkey,
+    pub price_lamports: u64,
+    pub items_minted: u64,
+}
@@ -0,0 +1,104 @@
+use {
+    crate::{ErrorCode, MetadataHistory, MetadataRevision},
+    anchor_lang::{prelude::*, solana_program::hash::hashv},
+    mpl_token_metadata::state::Metadata,
+};
+
+// hash of the fields that make up the visible identity of an nft. name, symbol and uri are hashed
+// as stored by metaplex, including the null byte padding.
+pub fn hash_data(metadata: &Metadata) -> Result<[u8; 32], ProgramError> {
+    let creators = metadata.data.creators.try_to_vec()?;
+
+    Ok(hashv(&[
+        metadata.data.name.as_bytes(),
+        metadata.data.symbol.as_bytes(),
+        metadata.data.uri.as_bytes(),
+        &creators,
+    ])
+    .to_bytes())
+}
+
+// history is opt-in per mint, see initialize_metadata_history. update instructions record a
+// revision when the history account of the mint is passed as a remaining account and has been
+// initialized; otherwise the update goes through without one. the metadata given here is the state
+// before the update, and the actor is the key behind the update, e.g. the vault authority rather
+// than the vault that signed it.
+pub fn record<'info>(
+    remaining_accounts: &[AccountInfo<'info>],
+    metadata: &Metadata,
+    actor: Pubkey,
+) -> ProgramResult {
+    let (history_key, _) =
+        Pubkey::find_program_address(&[MetadataHistory::SEED, metadata.mint.as_ref()], &crate::ID);
+
+    let history_info = match remaining_accounts.iter().find(|a| a.key() == history_key) {
+        Some(history_info) => history_info,
+        None => return Ok(()),
+    };
+    // not initialized; the mint didn't opt into history
+    if history_info.owner != &crate::ID {
+        return Ok(());
+    }
+    if !history_info.is_writable {
+        return Err(ErrorCode::HistoryNotWritable.into());
+    }
+
+    let mut history: Account<MetadataHistory> = Account::try_from(history_info)?;
+    let revision = MetadataRevision {
+        revision: history.revision_count,
+        slot: Clock::get()?.slot,
+        actor,
+        previous_hash: hash_data(metadata)?,
+    };
+    history.push(revision);
+
+    history.exit(&crate::ID)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn revision(revision: u64) -> MetadataRevision {
+        MetadataRevision {
+            revision,
+            slot: revision * 10,
+            actor: Pubkey::new_unique(),
+            previous_hash: [revision as u8; 32],
+        }
+    }
+
+    #[test]
+    fn push_appends_until_full() {
+        let mut history = MetadataHistory::default();
+        for i in 0..MetadataHistory::CAPACITY as u64 {
+            history.push(revision(i));
+        }
+
+        assert_eq!(history.revision_count, MetadataHistory::CAPACITY as u64);
+        assert_eq!(history.revisions.len(), MetadataHistory::CAPACITY);
+        for (i, r) in history.revisions.iter().enumerate() {
+            assert_eq!(r.revision, i as u64);
+        }
+    }
+
+    #[test]
+    fn push_overwrites_oldest_once_full() {
+        let mut history = MetadataHistory::default();
+        let total = MetadataHistory::CAPACITY as u64 + 3;
+        for i in 0..total {
+            history.push(revision(i));
+        }
+
+        assert_eq!(history.revision_count, total);
+        assert_eq!(history.revisions.len(), MetadataHistory::CAPACITY);
+        // the three oldest revisions were replaced by the three newest
+        assert_eq!(history.revisions[0].revision, 16);
+        assert_eq!(history.revisions[1].revision, 17);
+        assert_eq!(history.revisions[2].revision, 18);
+        assert_eq!(history.revisions[3].revision, 3);
+
+        // the buffer fits the account size
+        assert!(history.try_to_vec().unwrap().len() + 8 <= MetadataHistory::LEN);
+    }
+}
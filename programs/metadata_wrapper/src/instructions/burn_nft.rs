@@ -0,0 +1,100 @@
+use {
+    anchor_lang::prelude::*,
+    anchor_spl::token::Token,
+    mpl_token_metadata::instruction::burn_nft,
+    solana_program::{program::invoke_signed, system_instruction},
+};
+
+#[derive(Accounts)]
+pub struct BurnNft<'info> {
+    /// CHECK: verified via cpi into the metaplex metadata program
+    pub owner: AccountInfo<'info>,
+
+    /// CHECK: verified via cpi into the metaplex metadata program
+    pub metadata: AccountInfo<'info>,
+
+    /// CHECK: verified via cpi into the metaplex metadata program
+    pub mint: AccountInfo<'info>,
+
+    /// CHECK: verified via cpi into the metaplex metadata program
+    pub token_account: AccountInfo<'info>,
+
+    /// CHECK: verified via cpi into the metaplex metadata program
+    pub master_edition: AccountInfo<'info>,
+
+    // account receiving the rent of the closed accounts
+    /// CHECK: any account can receive lamports
+    pub receiver: AccountInfo<'info>,
+
+    /// CHECK: verified via cpi into the metaplex metadata program
+    #[account(address = mpl_token_metadata::id())]
+    pub token_metadata_program: UncheckedAccount<'info>,
+
+    #[account(address = spl_token::id())]
+    pub token_program: Program<'info, Token>,
+
+    pub system_program: Program<'info, System>,
+}
+
+// burns the token and closes the token account, metadata and master edition through metaplex.
+// metaplex refunds the rent of all three accounts to the owner, from where it's forwarded to the
+// receiver. metadata of a verified collection item can only be burned if the collection metadata
+// is passed as the first remaining account, so that metaplex can update the collection size.
+pub fn handle<'a, 'b, 'c, 'info>(
+    ctx: CpiContext<'a, 'b, 'c, 'info, BurnNft<'info>>,
+) -> ProgramResult {
+    msg!(
+        "Burning nft for mint = {}, rent receiver = {}",
+        ctx.accounts.mint.key(),
+        ctx.accounts.receiver.key()
+    );
+
+    let collection_metadata = ctx.remaining_accounts.first();
+    let mut account_infos = vec![
+        ctx.accounts.metadata.to_account_info(),
+        ctx.accounts.owner.to_account_info(),
+        ctx.accounts.mint.to_account_info(),
+        ctx.accounts.token_account.to_account_info(),
+        ctx.accounts.master_edition.to_account_info(),
+        ctx.accounts.token_program.to_account_info(),
+        ctx.accounts.token_metadata_program.to_account_info(),
+    ];
+    if let Some(collection_metadata) = collection_metadata {
+        account_infos.push(collection_metadata.clone());
+    }
+
+    let lamports_before = ctx.accounts.owner.lamports();
+    invoke_signed(
+        &burn_nft(
+            *ctx.accounts.token_metadata_program.key,
+            *ctx.accounts.metadata.key,
+            *ctx.accounts.owner.key,
+            *ctx.accounts.mint.key,
+            *ctx.accounts.token_account.key,
+            *ctx.accounts.master_edition.key,
+            *ctx.accounts.token_program.key,
+            collection_metadata.map(|account| *account.key),
+        ),
+        &account_infos,
+        ctx.signer_seeds,
+    )?;
+
+    let reclaimed = ctx.accounts.owner.lamports() - lamports_before;
+    if ctx.accounts.receiver.key() != ctx.accounts.owner.key() && reclaimed > 0 {
+        invoke_signed(
+            &system_instruction::transfer(
+                ctx.accounts.owner.key,
+                ctx.accounts.receiver.key,
+                reclaimed,
+            ),
+            &[
+                ctx.accounts.owner.to_account_info(),
+                ctx.accounts.receiver.to_account_info(),
+                ctx.accounts.system_program.to_account_info(),
+            ],
+            ctx.signer_seeds,
+        )?;
+    }
+
+    Ok(())
+}
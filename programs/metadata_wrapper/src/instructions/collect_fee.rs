@@ -0,0 +1,133 @@
+use {
+    crate::{ErrorCode, FeeCollected, TokenFeeCollected},
+    anchor_lang::{prelude::*, solana_program::system_program},
+    anchor_spl::token::{self, Token, TokenAccount, Transfer},
+    solana_program::{program::invoke, system_instruction},
+};
+
+#[derive(Accounts)]
+pub struct CollectFee<'info> {
+    /// CHECK: verified via cpi into the system program
+    #[account(mut)]
+    pub payer: AccountInfo<'info>,
+
+    /// CHECK: verified against the config by the caller
+    #[account(mut)]
+    pub treasury: AccountInfo<'info>,
+
+    #[account(address = system_program::id())]
+    pub system_program: Program<'info, System>,
+}
+
+// moves the fee from payer to treasury. the event is emitted even when the fee is waived, so that
+// every generate has a matching fee record.
+pub fn handle<'a, 'b, 'c, 'info>(
+    ctx: CpiContext<'a, 'b, 'c, 'info, CollectFee<'info>>,
+    mint: Pubkey,
+    lamports: u64,
+) -> ProgramResult {
+    let payer = ctx.accounts.payer.key();
+    let treasury = ctx.accounts.treasury.key();
+
+    pay(ctx, lamports)?;
+
+    emit!(FeeCollected {
+        mint,
+        payer,
+        treasury,
+        lamports,
+    });
+
+    Ok(())
+}
+
+// plain lamport transfer from payer to treasury, without a fee record
+pub fn pay<'a, 'b, 'c, 'info>(
+    ctx: CpiContext<'a, 'b, 'c, 'info, CollectFee<'info>>,
+    lamports: u64,
+) -> ProgramResult {
+    if lamports > 0 {
+        invoke(
+            &system_instruction::transfer(
+                ctx.accounts.payer.key,
+                ctx.accounts.treasury.key,
+                lamports,
+            ),
+            &[
+                ctx.accounts.payer.to_account_info(),
+                ctx.accounts.treasury.to_account_info(),
+                ctx.accounts.system_program.to_account_info(),
+            ],
+        )?;
+    }
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CollectTokenFee<'info> {
+    /// CHECK: verified via cpi into the spl token program
+    pub payer: AccountInfo<'info>,
+
+    /// CHECK: only used to verify the owner of the treasury token account
+    pub treasury: AccountInfo<'info>,
+
+    /// CHECK: verified in the instruction handler
+    #[account(mut)]
+    pub payer_token_account: AccountInfo<'info>,
+
+    /// CHECK: verified in the instruction handler
+    #[account(mut)]
+    pub treasury_token_account: AccountInfo<'info>,
+
+    #[account(address = spl_token::id())]
+    pub token_program: Program<'info, Token>,
+}
+
+// same as handle, but the fee is paid in the spl token configured as fee mint. both token accounts
+// are checked against the fee mint, so that a payer can't settle the fee in a worthless token.
+pub fn handle_token<'a, 'b, 'c, 'info>(
+    ctx: CpiContext<'a, 'b, 'c, 'info, CollectTokenFee<'info>>,
+    mint: Pubkey,
+    fee_mint: Pubkey,
+    amount: u64,
+) -> ProgramResult {
+    let payer_token_account: Account<TokenAccount> =
+        Account::try_from(&ctx.accounts.payer_token_account)?;
+    if payer_token_account.mint != fee_mint || payer_token_account.owner != ctx.accounts.payer.key()
+    {
+        return Err(ErrorCode::InvalidFeeTokenAccount.into());
+    }
+
+    let treasury_token_account: Account<TokenAccount> =
+        Account::try_from(&ctx.accounts.treasury_token_account)?;
+    if treasury_token_account.mint != fee_mint
+        || treasury_token_account.owner != ctx.accounts.treasury.key()
+    {
+        return Err(ErrorCode::InvalidFeeTokenAccount.into());
+    }
+
+    if amount > 0 {
+        token::transfer(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.payer_token_account.to_account_info(),
+                    to: ctx.accounts.treasury_token_account.to_account_info(),
+                    authority: ctx.accounts.payer.to_account_info(),
+                },
+            ),
+            amount,
+        )?;
+    }
+
+    emit!(TokenFeeCollected {
+        mint,
+        payer: ctx.accounts.payer.key(),
+        treasury: ctx.accounts.treasury.key(),
+        fee_mint,
+        amount,
+    });
+
+    Ok(())
+}
@@ -1,5 +1,5 @@
 use {
-    anchor_lang::prelude::*, anchor_spl::token::Token,
+    crate::ErrorCode, anchor_lang::prelude::*, anchor_spl::token::Token,
     mpl_token_metadata::instruction::create_master_edition_v3,
     solana_program::program::invoke_signed,
 };
@@ -42,14 +42,19 @@ pub struct CreateMasterEdition<'info> {
 
 pub fn handle<'a, 'b, 'c, 'info>(
     ctx: CpiContext<'a, 'b, 'c, 'info, CreateMasterEdition<'info>>,
-    supply: u64,
+    max_supply: Option<u64>,
 ) -> ProgramResult {
+    // no max supply means unlimited prints
     msg!(
-        "Creating master edition for mint = {}, supply = {}",
+        "Creating master edition for mint = {}, max supply = {:?}",
         ctx.accounts.mint.key(),
-        supply
+        max_supply
     );
 
+    if !ctx.accounts.master_edition.data_is_empty() {
+        return Err(ErrorCode::MasterEditionAlreadyExists.into());
+    }
+
     invoke_signed(
         &create_master_edition_v3(
             *ctx.accounts.token_metadata_program.key,
@@ -59,7 +64,7 @@ pub fn handle<'a, 'b, 'c, 'info>(
             *ctx.accounts.mint_authority.key,
             *ctx.accounts.metadata.key,
             *ctx.accounts.payer.key,
-            Some(supply),
+            max_supply,
         ),
         &[
             ctx.accounts.master_edition.to_account_info(),
@@ -1,6 +1,8 @@
 use {
-    crate::MetadataInfo, anchor_lang::prelude::*, anchor_spl::token::Token,
-    mpl_token_metadata::instruction::create_metadata_accounts_v2,
+    crate::{validation::validate_data, ErrorCode, MetadataInfo},
+    anchor_lang::prelude::*,
+    anchor_spl::token::Token,
+    mpl_token_metadata::{instruction::create_metadata_accounts_v2, state::DataV2},
     solana_program::program::invoke_signed,
 };
 
@@ -43,9 +45,22 @@ pub fn handle<'a, 'b, 'c, 'info>(
 ) -> ProgramResult {
     msg!("Creating metadata for mint = {}", ctx.accounts.mint.key());
 
-    let creators = metadata_info.clone().to_mpl_creators();
-    let collection = metadata_info.to_mpl_collection();
-    let uses = metadata_info.to_mpl_uses();
+    if !ctx.accounts.metadata.data_is_empty() {
+        return Err(ErrorCode::MetadataAlreadyExists.into());
+    }
+
+    // convert borrowed fields first, before name, symbol and uri are moved out of metadata_info
+    let data = DataV2 {
+        creators: metadata_info.to_mpl_creators(),
+        collection: metadata_info.to_mpl_collection(),
+        uses: metadata_info.to_mpl_uses(),
+        name: metadata_info.name,
+        symbol: metadata_info.symbol,
+        uri: metadata_info.uri,
+        seller_fee_basis_points: metadata_info.seller_fee_basis_points,
+    };
+
+    validate_data(&data)?;
 
     invoke_signed(
         &create_metadata_accounts_v2(
@@ -55,15 +70,15 @@ pub fn handle<'a, 'b, 'c, 'info>(
             ctx.accounts.mint_authority.key(),
             ctx.accounts.payer.key(),
             ctx.accounts.update_authority.key(),
-            metadata_info.name,
-            metadata_info.symbol,
-            metadata_info.uri,
-            creators,
-            metadata_info.seller_fee_basis_points,
+            data.name,
+            data.symbol,
+            data.uri,
+            data.creators,
+            data.seller_fee_basis_points,
             metadata_info.update_authority_is_signer,
             metadata_info.is_mutable,
-            collection,
-            uses,
+            data.collection,
+            data.uses,
         ),
         &[
             ctx.accounts.metadata.clone(),
@@ -0,0 +1,93 @@
+use {
+    crate::{
+        instructions::{
+            create_master_edition::CreateMasterEdition, create_metadata::CreateMetadata,
+            update_metadata::UpdateMetadata,
+        },
+        ErrorCode, FeePayment, GenerateContext, MasterEditionSupply, MetadataGenerated,
+        MetadataInfo,
+    },
+    anchor_lang::prelude::*,
+};
+
+// entrypoint of every instruction that creates an nft; charges the generate fee from the config in
+// lamports or in its fee mint, then runs the generate pipeline. for a token fee, the payer's and
+// treasury's token accounts of the fee mint are expected as the first remaining accounts. a content
+// hash given in the metadata info is stored in the content hash account of the mint, see
+// content_hash::record.
+pub fn process<'info>(
+    ctx: &GenerateContext<'info>,
+    remaining_accounts: &[AccountInfo<'info>],
+    metadata_info: MetadataInfo,
+    fee_payment: FeePayment,
+) -> ProgramResult {
+    let config = &ctx.unpaused.config;
+    match fee_payment {
+        FeePayment::Lamports => super::collect_fee::handle(
+            ctx.into_collect_fee_context(),
+            ctx.mint.key(),
+            config.generate_fee_lamports_for(&ctx.update_authority.key()),
+        )?,
+        FeePayment::Token => super::collect_fee::handle_token(
+            ctx.into_collect_token_fee_context(remaining_accounts)?,
+            ctx.mint.key(),
+            config.fee_mint.ok_or(ErrorCode::FeeMintNotSet)?,
+            config.generate_fee_tokens_for(&ctx.update_authority.key()),
+        )?,
+    };
+
+    let hash = metadata_info.content_hash;
+    handle(
+        ctx.into_create_metadata_context(),
+        ctx.into_create_master_edition_metadata_context(),
+        ctx.into_update_metadata_context(),
+        metadata_info,
+    )?;
+
+    crate::content_hash::record(remaining_accounts, &ctx.mint.key(), hash, false)
+}
+
+// shared generate pipeline
+// 1. create metadata for a given mint,
+// 2. optionally create a master edition, and
+// 3. toggle primary sale happened, optionally modify update authority
+pub fn handle<'a, 'b, 'c, 'info>(
+    create_metadata_ctx: CpiContext<'a, 'b, 'c, 'info, CreateMetadata<'info>>,
+    create_master_edition_ctx: CpiContext<'a, 'b, 'c, 'info, CreateMasterEdition<'info>>,
+    update_metadata_ctx: CpiContext<'a, 'b, 'c, 'info, UpdateMetadata<'info>>,
+    metadata_info: MetadataInfo,
+) -> ProgramResult {
+    let token_supply: MasterEditionSupply = metadata_info.supply.clone();
+    let new_update_authority: Option<Pubkey> = metadata_info.new_update_authority;
+
+    let event = MetadataGenerated {
+        mint: create_metadata_ctx.accounts.mint.key(),
+        metadata: create_metadata_ctx.accounts.metadata.key(),
+        payer: create_metadata_ctx.accounts.payer.key(),
+        name: metadata_info.name.clone(),
+        symbol: metadata_info.symbol.clone(),
+        uri: metadata_info.uri.clone(),
+        supply: token_supply.clone(),
+        update_authority: new_update_authority
+            .unwrap_or_else(|| create_metadata_ctx.accounts.update_authority.key()),
+        content_hash: metadata_info.content_hash,
+    };
+
+    super::create_metadata::handle(create_metadata_ctx, metadata_info)?;
+
+    match token_supply {
+        MasterEditionSupply::None => (),
+        MasterEditionSupply::Limited { max_supply } => {
+            super::create_master_edition::handle(create_master_edition_ctx, Some(max_supply))?
+        }
+        MasterEditionSupply::Unlimited => {
+            super::create_master_edition::handle(create_master_edition_ctx, None)?
+        }
+    };
+
+    super::update_metadata::update_for_primary_sale(update_metadata_ctx, new_update_authority)?;
+
+    emit!(event);
+
+    Ok(())
+}
@@ -1,4 +1,11 @@
+pub mod burn_nft;
+pub mod collect_fee;
 pub mod create_master_edition;
 pub mod create_metadata;
+pub mod generate;
+pub mod print_edition;
 pub mod sign_metadata;
 pub mod update_metadata;
+pub mod use_authority;
+pub mod utilize;
+pub mod verify_collection;
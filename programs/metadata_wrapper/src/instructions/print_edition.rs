@@ -0,0 +1,139 @@
+use {
+    crate::ErrorCode,
+    anchor_lang::prelude::*,
+    anchor_spl::token::Token,
+    mpl_token_metadata::{
+        instruction::mint_new_edition_from_master_edition_via_token,
+        pda::{find_edition_account, find_master_edition_account},
+        state::{get_master_edition, EDITION_MARKER_BIT_SIZE},
+    },
+    solana_program::program::invoke_signed,
+};
+
+#[derive(Accounts)]
+pub struct PrintEdition<'info> {
+    /// CHECK: verified via cpi into the metaplex metadata program
+    pub payer: AccountInfo<'info>,
+
+    // the following accounts aren't using anchor macros because CPI invocation
+    // will do the required validations.
+    /// CHECK: verified via cpi into the metaplex metadata program
+    pub new_metadata: AccountInfo<'info>,
+
+    /// CHECK: verified via cpi into the metaplex metadata program
+    pub new_edition: AccountInfo<'info>,
+
+    // mint of the printed edition; must already hold a single token
+    /// CHECK: verified via cpi into the metaplex metadata program
+    pub new_mint: AccountInfo<'info>,
+
+    /// CHECK: verified via cpi into the metaplex metadata program
+    pub new_mint_authority: AccountInfo<'info>,
+
+    // update authority of the printed edition's metadata
+    /// CHECK: verified via cpi into the metaplex metadata program
+    pub new_metadata_update_authority: AccountInfo<'info>,
+
+    /// CHECK: verified via cpi into the metaplex metadata program
+    pub edition_marker: AccountInfo<'info>,
+
+    /// CHECK: verified via cpi into the metaplex metadata program
+    pub master_edition: AccountInfo<'info>,
+
+    /// CHECK: verified via cpi into the metaplex metadata program
+    pub master_metadata: AccountInfo<'info>,
+
+    /// CHECK: verified via cpi into the metaplex metadata program
+    pub master_mint: AccountInfo<'info>,
+
+    // owner of the token account holding the master edition token
+    /// CHECK: verified via cpi into the metaplex metadata program
+    pub token_account_owner: AccountInfo<'info>,
+
+    /// CHECK: verified via cpi into the metaplex metadata program
+    pub token_account: AccountInfo<'info>,
+
+    /// CHECK: verified via cpi into the metaplex metadata program
+    #[account(address = mpl_token_metadata::id())]
+    pub token_metadata_program: UncheckedAccount<'info>,
+
+    #[account(address = spl_token::id())]
+    pub token_program: Program<'info, Token>,
+
+    pub system_program: Program<'info, System>,
+
+    pub rent: Sysvar<'info, Rent>,
+}
+
+pub fn handle<'a, 'b, 'c, 'info>(
+    ctx: CpiContext<'a, 'b, 'c, 'info, PrintEdition<'info>>,
+) -> ProgramResult {
+    // next edition is always the one after the current supply; editions are 1-indexed
+    let master_edition = get_master_edition(&ctx.accounts.master_edition)?;
+    if let Some(max_supply) = master_edition.max_supply() {
+        if master_edition.supply() >= max_supply {
+            return Err(ErrorCode::MaxSupplyReached.into());
+        }
+    }
+    let edition: u64 = master_edition.supply() + 1;
+
+    // every marker tracks EDITION_MARKER_BIT_SIZE editions
+    let (edition_marker, _) = find_edition_account(
+        ctx.accounts.master_mint.key,
+        (edition / EDITION_MARKER_BIT_SIZE).to_string(),
+    );
+    if edition_marker != ctx.accounts.edition_marker.key() {
+        return Err(ErrorCode::InvalidEditionMarker.into());
+    }
+
+    // a printed edition lives at the same seeds as a master edition, keyed by the new mint
+    let (new_edition, _) = find_master_edition_account(ctx.accounts.new_mint.key);
+    if new_edition != ctx.accounts.new_edition.key() {
+        return Err(ErrorCode::InvalidEdition.into());
+    }
+
+    msg!(
+        "Printing edition {} of master edition = {} to mint = {}",
+        edition,
+        ctx.accounts.master_edition.key(),
+        ctx.accounts.new_mint.key()
+    );
+
+    invoke_signed(
+        &mint_new_edition_from_master_edition_via_token(
+            *ctx.accounts.token_metadata_program.key,
+            *ctx.accounts.new_metadata.key,
+            *ctx.accounts.new_edition.key,
+            *ctx.accounts.master_edition.key,
+            *ctx.accounts.new_mint.key,
+            *ctx.accounts.new_mint_authority.key,
+            *ctx.accounts.payer.key,
+            *ctx.accounts.token_account_owner.key,
+            *ctx.accounts.token_account.key,
+            *ctx.accounts.new_metadata_update_authority.key,
+            *ctx.accounts.master_metadata.key,
+            *ctx.accounts.master_mint.key,
+            edition,
+        ),
+        &[
+            ctx.accounts.new_metadata.to_account_info(),
+            ctx.accounts.new_edition.to_account_info(),
+            ctx.accounts.master_edition.to_account_info(),
+            ctx.accounts.new_mint.to_account_info(),
+            ctx.accounts.edition_marker.to_account_info(),
+            ctx.accounts.new_mint_authority.to_account_info(),
+            ctx.accounts.payer.to_account_info(),
+            ctx.accounts.token_account_owner.to_account_info(),
+            ctx.accounts.token_account.to_account_info(),
+            ctx.accounts.new_metadata_update_authority.to_account_info(),
+            ctx.accounts.master_metadata.to_account_info(),
+            ctx.accounts.token_metadata_program.to_account_info(),
+            ctx.accounts.token_program.to_account_info(),
+            ctx.accounts.system_program.to_account_info(),
+            ctx.accounts.rent.to_account_info(),
+        ],
+        ctx.signer_seeds,
+    )?;
+
+    Ok(())
+}
@@ -1,5 +1,10 @@
 use {
-    anchor_lang::prelude::*, mpl_token_metadata::instruction::sign_metadata,
+    crate::{ErrorCode, MetadataSigned},
+    anchor_lang::prelude::*,
+    mpl_token_metadata::{
+        instruction::sign_metadata,
+        state::{Metadata, TokenMetadataAccount},
+    },
     solana_program::program::invoke,
 };
 
@@ -20,6 +25,17 @@ pub struct SignMetadata<'info> {
 pub fn handle<'a, 'b, 'c, 'info>(
     ctx: CpiContext<'a, 'b, 'c, 'info, SignMetadata<'info>>,
 ) -> ProgramResult {
+    let metadata: Metadata = Metadata::from_account_info(&ctx.accounts.metadata)?;
+    let is_creator: bool = match &metadata.data.creators {
+        Some(creators) => creators
+            .iter()
+            .any(|creator| creator.address == ctx.accounts.creator.key()),
+        None => false,
+    };
+    if !is_creator {
+        return Err(ErrorCode::CreatorNotFound.into());
+    }
+
     invoke(
         &sign_metadata(
             *ctx.accounts.token_metadata_program.key,
@@ -33,5 +49,11 @@ pub fn handle<'a, 'b, 'c, 'info>(
         ],
     )?;
 
+    emit!(MetadataSigned {
+        mint: metadata.mint,
+        metadata: ctx.accounts.metadata.key(),
+        creator: ctx.accounts.creator.key(),
+    });
+
     Ok(())
 }
@@ -1,8 +1,12 @@
 use {
+    crate::{
+        content_hash, history, validation::validate_data, ErrorCode, MetadataUpdated,
+        MetadataUriUpdated, QueuedMetadataChange,
+    },
     anchor_lang::{prelude::*, solana_program::system_program},
     mpl_token_metadata::{
         instruction::update_metadata_accounts_v2,
-        state::{Collection, Creator, DataV2, Metadata, Uses},
+        state::{Collection, Creator, DataV2, Metadata, TokenMetadataAccount, Uses},
     },
     solana_program::{
         instruction::Instruction,
@@ -27,6 +31,31 @@ pub struct UpdateMetadataInfo {
     pub collection: Option<Collection>,
     /// Uses
     pub uses: Option<Uses>,
+    /// SHA-256 of the JSON document at uri, optional
+    pub content_hash: Option<[u8; 32]>,
+}
+
+// same fields as UpdateMetadataInfo, but only the fields that are set will be changed. everything
+// else is read from the current metadata.
+#[repr(C)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default, PartialEq, Debug)]
+pub struct PatchMetadataInfo {
+    /// The name of the asset
+    pub name: Option<String>,
+    /// The symbol for the asset
+    pub symbol: Option<String>,
+    /// URI pointing to JSON representing the asset
+    pub uri: Option<String>,
+    /// Royalty basis points that goes to creators in secondary sales (0-10000)
+    pub seller_fee_basis_points: Option<u16>,
+    /// Array of creators
+    pub creators: Option<Vec<Creator>>,
+    /// Collection
+    pub collection: Option<Collection>,
+    /// Uses
+    pub uses: Option<Uses>,
+    /// SHA-256 of the JSON document at uri, optional
+    pub content_hash: Option<[u8; 32]>,
 }
 
 #[derive(Accounts)]
@@ -67,19 +96,48 @@ pub fn update_for_primary_sale<'a, 'b, 'c, 'info>(
     Ok(())
 }
 
+// only change update authority
+pub fn update_authority<'a, 'b, 'c, 'info>(
+    ctx: CpiContext<'a, 'b, 'c, 'info, UpdateMetadata<'info>>,
+    new_update_authority: Pubkey,
+) -> ProgramResult {
+    msg!(
+        "Updating metadata = {} to update authority = {}",
+        ctx.accounts.metadata.key(),
+        new_update_authority
+    );
+
+    handle(ctx, Some(new_update_authority), None, None)?;
+
+    Ok(())
+}
+
 // only update uri
 pub fn update_uri<'a, 'b, 'c, 'info>(
     ctx: CpiContext<'a, 'b, 'c, 'info, UpdateMetadata<'info>>,
     uri: String,
+    content_hash: Option<[u8; 32]>,
 ) -> ProgramResult {
-    msg!("yay, we reached the update uri function!");
+    msg!(
+        "Updating uri for metadata = {}",
+        ctx.accounts.metadata.key()
+    );
     // https://github.com/metaplex-foundation/metaplex-program-library/blob/ddb247622dcfd7501f6811007fbbb88b1bce1483/token-metadata/program/src/processor.rs#L261
-    let metadata = Metadata::from_account_info(&ctx.accounts.metadata)?;
+    let metadata: Metadata = Metadata::from_account_info(&ctx.accounts.metadata)?;
+
+    let event = MetadataUriUpdated {
+        mint: metadata.mint,
+        metadata: ctx.accounts.metadata.key(),
+        update_authority: ctx.accounts.update_authority.key(),
+        old_uri: metadata.data.uri.trim_matches(char::from(0)).to_string(),
+        new_uri: uri.clone(),
+        content_hash,
+    };
 
     let data_v2 = DataV2 {
         name: metadata.data.name,
         symbol: metadata.data.symbol,
-        uri: uri.to_string(), // updated uri
+        uri, // updated uri
         seller_fee_basis_points: metadata.data.seller_fee_basis_points,
         creators: metadata.data.creators,
         collection: metadata.collection,
@@ -88,14 +146,49 @@ pub fn update_uri<'a, 'b, 'c, 'info>(
 
     handle(ctx, None, Some(data_v2), None)?;
 
+    emit!(event);
+
     Ok(())
 }
 
+// applies a change queued in a timelock or approved by a multisig, recording history and the
+// content hash from the remaining accounts like the direct update instructions. the actor is the
+// key that queued or proposed the change, not whoever cranked it.
+pub fn apply_change<'a, 'b, 'c, 'info>(
+    ctx: CpiContext<'a, 'b, 'c, 'info, UpdateMetadata<'info>>,
+    remaining_accounts: &[AccountInfo<'info>],
+    change: QueuedMetadataChange,
+    actor: Pubkey,
+) -> ProgramResult {
+    let metadata: Metadata = Metadata::from_account_info(&ctx.accounts.metadata)?;
+
+    let (hash, uri_changed) = match &change {
+        QueuedMetadataChange::Data(data) => (
+            data.content_hash,
+            content_hash::uri_changed(&metadata, &data.uri),
+        ),
+        QueuedMetadataChange::Uri(uri, hash) => (*hash, content_hash::uri_changed(&metadata, uri)),
+        QueuedMetadataChange::UpdateAuthority(_) => (None, false),
+    };
+
+    match change {
+        QueuedMetadataChange::Data(data) => update_data(ctx, data)?,
+        QueuedMetadataChange::Uri(uri, hash) => update_uri(ctx, uri, hash)?,
+        QueuedMetadataChange::UpdateAuthority(new_update_authority) => {
+            update_authority(ctx, new_update_authority)?
+        }
+    };
+    history::record(remaining_accounts, &metadata, actor)?;
+    content_hash::record(remaining_accounts, &metadata.mint, hash, uri_changed)
+}
+
 // completely update metadata
 pub fn update_data<'a, 'b, 'c, 'info>(
     ctx: CpiContext<'a, 'b, 'c, 'info, UpdateMetadata<'info>>,
     data: UpdateMetadataInfo,
 ) -> ProgramResult {
+    let metadata: Metadata = Metadata::from_account_info(&ctx.accounts.metadata)?;
+
     let data_v2 = DataV2 {
         name: data.name,
         symbol: data.symbol,
@@ -106,8 +199,59 @@ pub fn update_data<'a, 'b, 'c, 'info>(
         uses: data.uses,
     };
 
+    handle_with_event(ctx, &metadata, data_v2, data.content_hash)
+}
+
+// only update the fields that are set, keep the rest as is
+pub fn patch_data<'a, 'b, 'c, 'info>(
+    ctx: CpiContext<'a, 'b, 'c, 'info, UpdateMetadata<'info>>,
+    patch: PatchMetadataInfo,
+) -> ProgramResult {
+    let metadata: Metadata = Metadata::from_account_info(&ctx.accounts.metadata)?;
+
+    // metaplex pads name, symbol and uri with null bytes; strip them so that untouched
+    // fields are written back as they were originally provided
+    let data_v2 = DataV2 {
+        name: patch
+            .name
+            .unwrap_or_else(|| metadata.data.name.trim_matches(char::from(0)).to_string()),
+        symbol: patch
+            .symbol
+            .unwrap_or_else(|| metadata.data.symbol.trim_matches(char::from(0)).to_string()),
+        uri: patch
+            .uri
+            .unwrap_or_else(|| metadata.data.uri.trim_matches(char::from(0)).to_string()),
+        seller_fee_basis_points: patch
+            .seller_fee_basis_points
+            .unwrap_or(metadata.data.seller_fee_basis_points),
+        creators: patch.creators.or_else(|| metadata.data.creators.clone()),
+        collection: patch.collection.or_else(|| metadata.collection.clone()),
+        uses: patch.uses.or_else(|| metadata.uses.clone()),
+    };
+
+    handle_with_event(ctx, &metadata, data_v2, patch.content_hash)
+}
+
+// updates data and emits the old and new values, not to be called publically
+fn handle_with_event<'a, 'b, 'c, 'info>(
+    ctx: CpiContext<'a, 'b, 'c, 'info, UpdateMetadata<'info>>,
+    metadata: &Metadata,
+    data_v2: DataV2,
+    content_hash: Option<[u8; 32]>,
+) -> ProgramResult {
+    let event = MetadataUpdated {
+        mint: metadata.mint,
+        metadata: ctx.accounts.metadata.key(),
+        update_authority: ctx.accounts.update_authority.key(),
+        old_data: crate::DataV2::from_mpl_metadata(metadata),
+        new_data: crate::DataV2::from_mpl(&data_v2),
+        content_hash,
+    };
+
     handle(ctx, None, Some(data_v2), None)?;
 
+    emit!(event);
+
     Ok(())
 }
 
@@ -118,6 +262,17 @@ fn handle<'a, 'b, 'c, 'info>(
     data: Option<DataV2>,
     primary_sale_happened: Option<bool>,
 ) -> ProgramResult {
+    let metadata: Metadata = Metadata::from_account_info(&ctx.accounts.metadata)?;
+    if metadata.update_authority != ctx.accounts.update_authority.key() {
+        return Err(ErrorCode::UpdateAuthorityIncorrect.into());
+    }
+    if let Some(data) = &data {
+        if !metadata.is_mutable {
+            return Err(ErrorCode::MetadataImmutable.into());
+        }
+        validate_data(data)?;
+    }
+
     let ix: &Instruction = &update_metadata_accounts_v2(
         *ctx.accounts.token_metadata_program.key,
         *ctx.accounts.metadata.key,
@@ -0,0 +1,145 @@
+use {
+    crate::ErrorCode,
+    anchor_lang::prelude::*,
+    anchor_spl::token::Token,
+    mpl_token_metadata::{
+        instruction::{approve_use_authority, revoke_use_authority},
+        pda::{find_program_as_burner_account, find_use_authority_account},
+        state::{Metadata, TokenMetadataAccount},
+    },
+    solana_program::{instruction::Instruction, program::invoke_signed},
+};
+
+#[derive(Accounts)]
+pub struct UseAuthority<'info> {
+    /// CHECK: derivation is verified in the instruction handler
+    pub use_authority_record: AccountInfo<'info>,
+
+    /// CHECK: verified via cpi into the metaplex metadata program
+    pub owner: AccountInfo<'info>,
+
+    /// CHECK: verified via cpi into the metaplex metadata program
+    pub payer: AccountInfo<'info>,
+
+    // account that is (or was) allowed to use the nft
+    /// CHECK: verified via cpi into the metaplex metadata program
+    pub user: AccountInfo<'info>,
+
+    /// CHECK: verified via cpi into the metaplex metadata program
+    pub owner_token_account: AccountInfo<'info>,
+
+    /// CHECK: verified via cpi into the metaplex metadata program
+    pub metadata: AccountInfo<'info>,
+
+    /// CHECK: verified via cpi into the metaplex metadata program
+    pub mint: AccountInfo<'info>,
+
+    /// CHECK: derivation is verified in the instruction handler
+    pub burner: AccountInfo<'info>,
+
+    /// CHECK: verified via cpi into the metaplex metadata program
+    #[account(address = mpl_token_metadata::id())]
+    pub token_metadata_program: UncheckedAccount<'info>,
+
+    #[account(address = spl_token::id())]
+    pub token_program: Program<'info, Token>,
+
+    pub system_program: Program<'info, System>,
+
+    pub rent: Sysvar<'info, Rent>,
+}
+
+// allow user to call utilize on the nft up to number_of_uses times
+pub fn approve<'a, 'b, 'c, 'info>(
+    ctx: CpiContext<'a, 'b, 'c, 'info, UseAuthority<'info>>,
+    number_of_uses: u64,
+) -> ProgramResult {
+    let metadata: Metadata = Metadata::from_account_info(&ctx.accounts.metadata)?;
+    let uses = metadata.uses.ok_or(ErrorCode::MetadataNotUsable)?;
+    if number_of_uses > uses.remaining {
+        return Err(ErrorCode::NotEnoughUses.into());
+    }
+
+    let (burner, _) = find_program_as_burner_account();
+    if burner != ctx.accounts.burner.key() {
+        return Err(ErrorCode::InvalidBurner.into());
+    }
+
+    msg!(
+        "Approving use authority = {} for mint = {}, number of uses = {}",
+        ctx.accounts.user.key(),
+        ctx.accounts.mint.key(),
+        number_of_uses
+    );
+
+    let ix: &Instruction = &approve_use_authority(
+        *ctx.accounts.token_metadata_program.key,
+        *ctx.accounts.use_authority_record.key,
+        *ctx.accounts.user.key,
+        *ctx.accounts.owner.key,
+        *ctx.accounts.payer.key,
+        *ctx.accounts.owner_token_account.key,
+        *ctx.accounts.metadata.key,
+        *ctx.accounts.mint.key,
+        burner,
+        number_of_uses,
+    );
+
+    handle(ctx, ix)
+}
+
+// remove a previously approved use authority
+pub fn revoke<'a, 'b, 'c, 'info>(
+    ctx: CpiContext<'a, 'b, 'c, 'info, UseAuthority<'info>>,
+) -> ProgramResult {
+    msg!(
+        "Revoking use authority = {} for mint = {}",
+        ctx.accounts.user.key(),
+        ctx.accounts.mint.key()
+    );
+
+    let ix: &Instruction = &revoke_use_authority(
+        *ctx.accounts.token_metadata_program.key,
+        *ctx.accounts.use_authority_record.key,
+        *ctx.accounts.user.key,
+        *ctx.accounts.owner.key,
+        *ctx.accounts.owner_token_account.key,
+        *ctx.accounts.metadata.key,
+        *ctx.accounts.mint.key,
+    );
+
+    handle(ctx, ix)
+}
+
+// shared handler function, not to be called publically
+fn handle<'a, 'b, 'c, 'info>(
+    ctx: CpiContext<'a, 'b, 'c, 'info, UseAuthority<'info>>,
+    ix: &Instruction,
+) -> ProgramResult {
+    let (use_authority_record, _) =
+        find_use_authority_account(ctx.accounts.mint.key, ctx.accounts.user.key);
+    if use_authority_record != ctx.accounts.use_authority_record.key() {
+        return Err(ErrorCode::InvalidUseAuthorityRecord.into());
+    }
+
+    invoke_signed(
+        ix,
+        &[
+            ctx.accounts.use_authority_record.to_account_info(),
+            ctx.accounts.owner.to_account_info(),
+            ctx.accounts.payer.to_account_info(),
+            ctx.accounts.user.to_account_info(),
+            ctx.accounts.owner_token_account.to_account_info(),
+            ctx.accounts.metadata.to_account_info(),
+            ctx.accounts.mint.to_account_info(),
+            ctx.accounts.burner.to_account_info(),
+            ctx.accounts.token_metadata_program.to_account_info(),
+            ctx.accounts.token_program.to_account_info(),
+            ctx.accounts.system_program.to_account_info(),
+            ctx.accounts.rent.to_account_info(),
+        ],
+        ctx.signer_seeds,
+    )?;
+
+    Ok(())
+}
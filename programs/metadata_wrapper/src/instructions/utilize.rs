@@ -0,0 +1,135 @@
+use {
+    crate::{ErrorCode, MetadataUsed},
+    anchor_lang::prelude::*,
+    anchor_spl::token::Token,
+    mpl_token_metadata::{
+        instruction::utilize,
+        pda::{find_program_as_burner_account, find_use_authority_account},
+        state::{Metadata, TokenMetadataAccount, UseMethod},
+    },
+    solana_program::program::invoke_signed,
+};
+
+#[derive(Accounts)]
+pub struct Utilize<'info> {
+    /// CHECK: verified via cpi into the metaplex metadata program
+    pub metadata: AccountInfo<'info>,
+
+    /// CHECK: verified via cpi into the metaplex metadata program
+    pub token_account: AccountInfo<'info>,
+
+    /// CHECK: verified via cpi into the metaplex metadata program
+    pub mint: AccountInfo<'info>,
+
+    // either the owner of the token or a delegated use authority
+    /// CHECK: verified via cpi into the metaplex metadata program
+    pub use_authority: AccountInfo<'info>,
+
+    /// CHECK: verified via cpi into the metaplex metadata program
+    pub owner: AccountInfo<'info>,
+
+    // only used when a delegated use authority is using the nft
+    /// CHECK: derivation is verified in the instruction handler
+    pub use_authority_record: AccountInfo<'info>,
+
+    // only used when a delegated use authority burns the nft on its last use
+    /// CHECK: derivation is verified in the instruction handler
+    pub burner: AccountInfo<'info>,
+
+    /// CHECK: verified via cpi into the metaplex metadata program
+    #[account(address = mpl_token_metadata::id())]
+    pub token_metadata_program: UncheckedAccount<'info>,
+
+    #[account(address = spl_token::id())]
+    pub token_program: Program<'info, Token>,
+
+    /// CHECK: verified via cpi into the metaplex metadata program
+    pub associated_token_program: AccountInfo<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    pub rent: Sysvar<'info, Rent>,
+}
+
+pub fn handle<'a, 'b, 'c, 'info>(
+    ctx: CpiContext<'a, 'b, 'c, 'info, Utilize<'info>>,
+    number_of_uses: u64,
+) -> ProgramResult {
+    let metadata: Metadata = Metadata::from_account_info(&ctx.accounts.metadata)?;
+    let uses = metadata.uses.ok_or(ErrorCode::MetadataNotUsable)?;
+
+    // burn & multiple can consume any number of remaining uses, single only ever one at a time
+    if uses.use_method == UseMethod::Single && number_of_uses != 1 {
+        return Err(ErrorCode::InvalidNumberOfUses.into());
+    }
+    if number_of_uses > uses.remaining {
+        return Err(ErrorCode::NotEnoughUses.into());
+    }
+
+    let (use_authority_record, _) =
+        find_use_authority_account(ctx.accounts.mint.key, ctx.accounts.use_authority.key);
+    if use_authority_record != ctx.accounts.use_authority_record.key() {
+        return Err(ErrorCode::InvalidUseAuthorityRecord.into());
+    }
+
+    let (burner, _) = find_program_as_burner_account();
+    if burner != ctx.accounts.burner.key() {
+        return Err(ErrorCode::InvalidBurner.into());
+    }
+
+    // metaplex treats the instruction as delegated when the record and burner accounts are present
+    let is_delegated: bool = ctx.accounts.use_authority.key() != ctx.accounts.owner.key();
+    let (use_authority_record, burner) = match is_delegated {
+        true => (Some(use_authority_record), Some(burner)),
+        false => (None, None),
+    };
+
+    msg!(
+        "Using metadata for mint = {}, number of uses = {}, delegated = {}",
+        ctx.accounts.mint.key(),
+        number_of_uses,
+        is_delegated
+    );
+
+    invoke_signed(
+        &utilize(
+            *ctx.accounts.token_metadata_program.key,
+            *ctx.accounts.metadata.key,
+            *ctx.accounts.token_account.key,
+            *ctx.accounts.mint.key,
+            use_authority_record,
+            *ctx.accounts.use_authority.key,
+            *ctx.accounts.owner.key,
+            burner,
+            number_of_uses,
+        ),
+        &[
+            ctx.accounts.metadata.to_account_info(),
+            ctx.accounts.token_account.to_account_info(),
+            ctx.accounts.mint.to_account_info(),
+            ctx.accounts.use_authority.to_account_info(),
+            ctx.accounts.owner.to_account_info(),
+            ctx.accounts.use_authority_record.to_account_info(),
+            ctx.accounts.burner.to_account_info(),
+            ctx.accounts.token_metadata_program.to_account_info(),
+            ctx.accounts.token_program.to_account_info(),
+            ctx.accounts.associated_token_program.to_account_info(),
+            ctx.accounts.system_program.to_account_info(),
+            ctx.accounts.rent.to_account_info(),
+        ],
+        ctx.signer_seeds,
+    )?;
+
+    // re-read metadata so that the emitted count reflects what metaplex stored
+    let metadata: Metadata = Metadata::from_account_info(&ctx.accounts.metadata)?;
+    let remaining: u64 = metadata.uses.map_or(0, |uses| uses.remaining);
+
+    emit!(MetadataUsed {
+        mint: ctx.accounts.mint.key(),
+        use_authority: ctx.accounts.use_authority.key(),
+        number_of_uses,
+        remaining,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,158 @@
+use {
+    anchor_lang::prelude::*,
+    mpl_token_metadata::instruction::{
+        set_and_verify_collection, unverify_collection, verify_collection,
+    },
+    solana_program::{instruction::Instruction, program::invoke_signed},
+};
+
+#[derive(Accounts)]
+pub struct VerifyCollection<'info> {
+    /// CHECK: verified via cpi into the metaplex metadata program
+    #[account(mut)]
+    pub metadata: AccountInfo<'info>,
+
+    // update authority of the collection nft. can be a pda, in which case the
+    // caller is expected to provide signer seeds. metaplex marks it writable.
+    /// CHECK: verified via cpi into the metaplex metadata program
+    #[account(mut)]
+    pub collection_authority: AccountInfo<'info>,
+
+    /// CHECK: verified via cpi into the metaplex metadata program
+    pub payer: AccountInfo<'info>,
+
+    /// CHECK: verified via cpi into the metaplex metadata program
+    pub collection_mint: AccountInfo<'info>,
+
+    /// CHECK: verified via cpi into the metaplex metadata program
+    pub collection_metadata: AccountInfo<'info>,
+
+    /// CHECK: verified via cpi into the metaplex metadata program
+    pub collection_master_edition: AccountInfo<'info>,
+
+    /// CHECK: verified via cpi into the metaplex metadata program
+    #[account(address = spl_token_metadata::id())]
+    pub token_metadata_program: AccountInfo<'info>,
+}
+
+#[derive(Accounts)]
+pub struct SetAndVerifyCollection<'info> {
+    pub verify: VerifyCollection<'info>,
+
+    // update authority of the metadata, required when setting the collection
+    /// CHECK: verified via cpi into the metaplex metadata program
+    pub update_authority: AccountInfo<'info>,
+}
+
+// mark the collection on metadata as verified. collection must already be set on the metadata.
+pub fn verify<'a, 'b, 'c, 'info>(
+    ctx: CpiContext<'a, 'b, 'c, 'info, VerifyCollection<'info>>,
+) -> ProgramResult {
+    msg!(
+        "Verifying collection = {} for metadata = {}",
+        ctx.accounts.collection_mint.key(),
+        ctx.accounts.metadata.key()
+    );
+
+    let ix: &Instruction = &verify_collection(
+        *ctx.accounts.token_metadata_program.key,
+        *ctx.accounts.metadata.key,
+        *ctx.accounts.collection_authority.key,
+        *ctx.accounts.payer.key,
+        *ctx.accounts.collection_mint.key,
+        *ctx.accounts.collection_metadata.key,
+        *ctx.accounts.collection_master_edition.key,
+        None, // no delegated collection authority
+    );
+
+    handle(ctx, ix)
+}
+
+// mark the collection on metadata as unverified
+pub fn unverify<'a, 'b, 'c, 'info>(
+    ctx: CpiContext<'a, 'b, 'c, 'info, VerifyCollection<'info>>,
+) -> ProgramResult {
+    msg!(
+        "Unverifying collection = {} for metadata = {}",
+        ctx.accounts.collection_mint.key(),
+        ctx.accounts.metadata.key()
+    );
+
+    let ix: &Instruction = &unverify_collection(
+        *ctx.accounts.token_metadata_program.key,
+        *ctx.accounts.metadata.key,
+        *ctx.accounts.collection_authority.key,
+        *ctx.accounts.collection_mint.key,
+        *ctx.accounts.collection_metadata.key,
+        *ctx.accounts.collection_master_edition.key,
+        None, // no delegated collection authority
+    );
+
+    handle(ctx, ix)
+}
+
+// set the collection on metadata and verify it in one go. update authority of the metadata
+// must be the same as the collection authority.
+pub fn set_and_verify<'a, 'b, 'c, 'info>(
+    ctx: CpiContext<'a, 'b, 'c, 'info, SetAndVerifyCollection<'info>>,
+) -> ProgramResult {
+    let accounts = &ctx.accounts.verify;
+
+    msg!(
+        "Setting and verifying collection = {} for metadata = {}",
+        accounts.collection_mint.key(),
+        accounts.metadata.key()
+    );
+
+    let ix: &Instruction = &set_and_verify_collection(
+        *accounts.token_metadata_program.key,
+        *accounts.metadata.key,
+        *accounts.collection_authority.key,
+        *accounts.payer.key,
+        *ctx.accounts.update_authority.key,
+        *accounts.collection_mint.key,
+        *accounts.collection_metadata.key,
+        *accounts.collection_master_edition.key,
+        None, // no delegated collection authority
+    );
+
+    invoke_signed(
+        ix,
+        &[
+            accounts.token_metadata_program.to_account_info(),
+            accounts.metadata.to_account_info(),
+            accounts.collection_authority.to_account_info(),
+            accounts.payer.to_account_info(),
+            ctx.accounts.update_authority.to_account_info(),
+            accounts.collection_mint.to_account_info(),
+            accounts.collection_metadata.to_account_info(),
+            accounts.collection_master_edition.to_account_info(),
+        ],
+        ctx.signer_seeds,
+    )?;
+
+    Ok(())
+}
+
+// shared handler function, not to be called publically. signer seeds are forwarded so that
+// a pda collection authority can sign.
+fn handle<'a, 'b, 'c, 'info>(
+    ctx: CpiContext<'a, 'b, 'c, 'info, VerifyCollection<'info>>,
+    ix: &Instruction,
+) -> ProgramResult {
+    invoke_signed(
+        ix,
+        &[
+            ctx.accounts.token_metadata_program.to_account_info(),
+            ctx.accounts.metadata.to_account_info(),
+            ctx.accounts.collection_authority.to_account_info(),
+            ctx.accounts.payer.to_account_info(),
+            ctx.accounts.collection_mint.to_account_info(),
+            ctx.accounts.collection_metadata.to_account_info(),
+            ctx.accounts.collection_master_edition.to_account_info(),
+        ],
+        ctx.signer_seeds,
+    )?;
+
+    Ok(())
+}
@@ -1,11 +1,21 @@
 use anchor_lang::prelude::*;
 
+pub mod content_hash;
 pub mod context;
+pub mod error;
+pub mod event;
+pub mod history;
 pub mod instructions;
+pub mod merkle;
+pub mod state;
+pub mod validation;
 
 pub use context::*;
+pub use error::ErrorCode;
+pub use event::*;
 pub use instructions::*;
-pub use instructions::update_metadata::UpdateMetadataInfo;
+pub use state::*;
+pub use instructions::update_metadata::{PatchMetadataInfo, UpdateMetadataInfo};
 
 use {
     anchor_lang::solana_program::borsh::try_from_slice_unchecked,
@@ -27,26 +37,43 @@ pub mod metadata_wrapper {
     // 1. create metadata for a given mint,
     // 2. optionally create a master edition, aand
     // 3. toggle primary sale happened, optionally modify update authority
-    pub fn generate(ctx: Context<GenerateContext>, metadata_info: MetadataInfo) -> ProgramResult {
-        let token_supply: Option<u64> = metadata_info.supply;
-        let new_update_authority: Option<Pubkey> = metadata_info.new_update_authority;
-
-        instructions::create_metadata::handle(
-            ctx.accounts.into_create_metadata_context(),
+    //
+    // payer is charged the generate fee from the config, unless the update authority is waived. the
+    // fee is paid either in lamports or, when the config has a fee mint, in that spl token; for the
+    // latter, the payer's and treasury's token accounts are expected as remaining accounts.
+    // setting new_update_authority to an authority vault hands control of the metadata to the
+    // program; see initialize_authority_vault.
+    pub fn generate<'info>(
+        ctx: Context<'_, '_, '_, 'info, GenerateContext<'info>>,
+        metadata_info: MetadataInfo,
+        fee_payment: FeePayment,
+    ) -> ProgramResult {
+        instructions::generate::process(
+            ctx.accounts,
+            ctx.remaining_accounts,
             metadata_info,
+            fee_payment,
         )?;
 
-        match token_supply {
-            Some(supply) => instructions::create_master_edition::handle(
-                ctx.accounts.into_create_master_edition_metadata_context(),
-                supply,
-            )?,
-            None => (),
-        };
+        Ok(())
+    }
 
-        instructions::update_metadata::update_for_primary_sale(
-            ctx.accounts.into_update_metadata_context(),
-            new_update_authority,
+    // same as generate, but the mint doesn't need to exist beforehand. initializes the mint, creates
+    // the recipient's associated token account and mints a single token before creating metadata,
+    // so that a whole nft can be created with one instruction.
+    pub fn generate_with_mint<'info>(
+        ctx: Context<'_, '_, '_, 'info, GenerateWithMintContext<'info>>,
+        metadata_info: MetadataInfo,
+        fee_payment: FeePayment,
+    ) -> ProgramResult {
+        anchor_spl::token::mint_to(ctx.accounts.into_mint_to_context(), 1)?;
+
+        let generate = ctx.accounts.into_generate_context()?;
+        instructions::generate::process(
+            &generate,
+            ctx.remaining_accounts,
+            metadata_info,
+            fee_payment,
         )?;
 
         Ok(())
@@ -59,11 +86,104 @@ pub mod metadata_wrapper {
         Ok(())
     }
 
+    // print the next numbered edition from a master edition. fails once max supply is reached.
+    pub fn print_edition(ctx: Context<PrintEditionContext>) -> ProgramResult {
+        instructions::print_edition::handle(ctx.accounts.into_print_edition_context())?;
+
+        Ok(())
+    }
+
+    // collection update authority can verify that metadata belongs to its collection. a collection
+    // nft held by an authority vault is verified through vault_verify_collection; the other program
+    // owned authorities (multisig, timelock, drop) can't act as collection authority.
+    pub fn verify_collection(ctx: Context<VerifyCollectionContext>) -> ProgramResult {
+        instructions::verify_collection::verify(ctx.accounts.into_verify_collection_context())?;
+
+        Ok(())
+    }
+
+    // collection update authority can remove a previous verification
+    pub fn unverify_collection(ctx: Context<VerifyCollectionContext>) -> ProgramResult {
+        instructions::verify_collection::unverify(ctx.accounts.into_verify_collection_context())?;
+
+        Ok(())
+    }
+
+    // set collection on metadata and verify it in a single instruction. only possible when the
+    // metadata and the collection share the same update authority.
+    pub fn set_and_verify_collection(ctx: Context<SetAndVerifyCollectionContext>) -> ProgramResult {
+        instructions::verify_collection::set_and_verify(
+            ctx.accounts.into_set_and_verify_collection_context(),
+        )?;
+
+        Ok(())
+    }
+
+    // token owner can burn their nft. closes the token account, metadata and master edition, and
+    // sends their rent to the receiver. items of a verified collection need the collection
+    // metadata as remaining account.
+    pub fn burn_nft<'info>(
+        ctx: Context<'_, '_, '_, 'info, BurnNftContext<'info>>,
+    ) -> ProgramResult {
+        instructions::burn_nft::handle(
+            ctx.accounts
+                .into_burn_nft_context()
+                .with_remaining_accounts(ctx.remaining_accounts.to_vec()),
+        )?;
+
+        Ok(())
+    }
+
+    // token owner or an approved use authority can consume uses of the nft. when the use method
+    // is burn, the nft is burned once no uses remain.
+    pub fn utilize(ctx: Context<UtilizeContext>, number_of_uses: u64) -> ProgramResult {
+        instructions::utilize::handle(ctx.accounts.into_utilize_context(), number_of_uses)?;
+
+        Ok(())
+    }
+
+    // token owner can delegate uses of the nft to another account
+    pub fn approve_use_authority(
+        ctx: Context<UseAuthorityContext>,
+        number_of_uses: u64,
+    ) -> ProgramResult {
+        instructions::use_authority::approve(
+            ctx.accounts.into_use_authority_context(),
+            number_of_uses,
+        )?;
+
+        Ok(())
+    }
+
+    // token owner can revoke a previously delegated use authority
+    pub fn revoke_use_authority(ctx: Context<UseAuthorityContext>) -> ProgramResult {
+        instructions::use_authority::revoke(ctx.accounts.into_use_authority_context())?;
+
+        Ok(())
+    }
+
     // a
-    pub fn update_metadata_uri(ctx: Context<UpdateMetadataContext>, uri: String) -> ProgramResult {
+    pub fn update_metadata_uri(
+        ctx: Context<UpdateMetadataContext>,
+        uri: String,
+        content_hash: Option<[u8; 32]>,
+    ) -> ProgramResult {
+        let uri_changed = content_hash::uri_changed(&ctx.accounts.metadata, &uri);
         instructions::update_metadata::update_uri(
             ctx.accounts.into_update_metadata_context(),
             uri,
+            content_hash,
+        )?;
+        history::record(
+            ctx.remaining_accounts,
+            &ctx.accounts.metadata,
+            ctx.accounts.update_authority.key(),
+        )?;
+        content_hash::record(
+            ctx.remaining_accounts,
+            &ctx.accounts.metadata.mint,
+            content_hash,
+            uri_changed,
         )?;
 
         Ok(())
@@ -75,10 +195,1025 @@ pub mod metadata_wrapper {
         ctx: Context<UpdateMetadataContext>,
         data: UpdateMetadataInfo,
     ) -> ProgramResult {
+        let hash = data.content_hash;
+        let uri_changed = content_hash::uri_changed(&ctx.accounts.metadata, &data.uri);
+
         instructions::update_metadata::update_data(
             ctx.accounts.into_update_metadata_context(),
             data,
         )?;
+        history::record(
+            ctx.remaining_accounts,
+            &ctx.accounts.metadata,
+            ctx.accounts.update_authority.key(),
+        )?;
+        content_hash::record(
+            ctx.remaining_accounts,
+            &ctx.accounts.metadata.mint,
+            hash,
+            uri_changed,
+        )?;
+
+        Ok(())
+    }
+
+    // update authority can change only the given fields of the associated metadata; fields that
+    // aren't set are kept as is. same is_mutable restriction as update_metadata applies.
+    pub fn patch_metadata(
+        ctx: Context<UpdateMetadataContext>,
+        patch: PatchMetadataInfo,
+    ) -> ProgramResult {
+        let hash = patch.content_hash;
+        let uri_changed = patch.uri.as_ref().map_or(false, |uri| {
+            content_hash::uri_changed(&ctx.accounts.metadata, uri)
+        });
+
+        instructions::update_metadata::patch_data(
+            ctx.accounts.into_update_metadata_context(),
+            patch,
+        )?;
+        history::record(
+            ctx.remaining_accounts,
+            &ctx.accounts.metadata,
+            ctx.accounts.update_authority.key(),
+        )?;
+        content_hash::record(
+            ctx.remaining_accounts,
+            &ctx.accounts.metadata.mint,
+            hash,
+            uri_changed,
+        )?;
+
+        Ok(())
+    }
+
+    // first step of an update authority transfer; records the proposed authority and hands the
+    // metadata to the pending transfer account, which holds it until the transfer is accepted or
+    // cancelled. the metadata can't be updated in the meantime.
+    pub fn propose_update_authority(
+        ctx: Context<ProposeUpdateAuthorityContext>,
+        new_update_authority: Pubkey,
+        bump: u8,
+    ) -> ProgramResult {
+        let pending = &mut ctx.accounts.pending_update_authority;
+        pending.metadata = ctx.accounts.metadata.key();
+        pending.update_authority = ctx.accounts.update_authority.key();
+        pending.proposed_update_authority = new_update_authority;
+        pending.bump = bump;
+
+        instructions::update_metadata::update_authority(
+            ctx.accounts.into_update_metadata_context(),
+            ctx.accounts.pending_update_authority.key(),
+        )?;
+
+        emit!(UpdateAuthorityProposed {
+            mint: ctx.accounts.metadata.mint,
+            metadata: ctx.accounts.metadata.key(),
+            update_authority: ctx.accounts.update_authority.key(),
+            proposed_update_authority: new_update_authority,
+        });
+
+        Ok(())
+    }
+
+    // second step of an update authority transfer; only runs once the proposed authority signs,
+    // which guarantees that the new authority is a key someone actually controls. the pending
+    // transfer account signs the metadata over, so the proposing authority doesn't sign again.
+    pub fn accept_update_authority(ctx: Context<AcceptUpdateAuthorityContext>) -> ProgramResult {
+        let seeds = ctx.accounts.pending_update_authority.signer_seeds();
+
+        instructions::update_metadata::update_authority(
+            ctx.accounts
+                .into_update_metadata_context()
+                .with_signer(&[&seeds[..]]),
+            ctx.accounts.new_update_authority.key(),
+        )?;
+        history::record(
+            ctx.remaining_accounts,
+            &ctx.accounts.metadata,
+            ctx.accounts.new_update_authority.key(),
+        )?;
+
+        emit!(UpdateAuthorityAccepted {
+            mint: ctx.accounts.metadata.mint,
+            metadata: ctx.accounts.metadata.key(),
+            old_update_authority: ctx.accounts.update_authority.key(),
+            new_update_authority: ctx.accounts.new_update_authority.key(),
+        });
+
+        Ok(())
+    }
+
+    // proposing authority can drop a pending transfer at any time before it's accepted, and gets
+    // the metadata back
+    pub fn cancel_update_authority_transfer(
+        ctx: Context<CancelUpdateAuthorityTransferContext>,
+    ) -> ProgramResult {
+        let seeds = ctx.accounts.pending_update_authority.signer_seeds();
+
+        instructions::update_metadata::update_authority(
+            ctx.accounts
+                .into_update_metadata_context()
+                .with_signer(&[&seeds[..]]),
+            ctx.accounts.update_authority.key(),
+        )?;
+
+        emit!(UpdateAuthorityTransferCancelled {
+            metadata: ctx.accounts.pending_update_authority.metadata,
+            update_authority: ctx.accounts.update_authority.key(),
+            proposed_update_authority: ctx
+                .accounts
+                .pending_update_authority
+                .proposed_update_authority,
+        });
+
+        Ok(())
+    }
+
+    // creates the program owned update authority for a collection. metadata whose update authority
+    // is the vault can only be changed once the vault authority approves the change. only the
+    // update authority of the collection nft can create its vault, and starts out as its authority.
+    pub fn initialize_authority_vault(
+        ctx: Context<InitializeAuthorityVaultContext>,
+        collection: Pubkey,
+        bump: u8,
+    ) -> ProgramResult {
+        let vault = &mut ctx.accounts.vault;
+        vault.collection = collection;
+        vault.authority = ctx.accounts.authority.key();
+        vault.bump = bump;
+
+        Ok(())
+    }
+
+    // vault authority can hand approval of vault changes to another account
+    pub fn set_vault_authority(
+        ctx: Context<SetVaultAuthorityContext>,
+        new_authority: Pubkey,
+    ) -> ProgramResult {
+        let vault = &mut ctx.accounts.vault;
+
+        emit!(VaultAuthorityUpdated {
+            vault: vault.key(),
+            collection: vault.collection,
+            old_authority: vault.authority,
+            new_authority,
+        });
+
+        vault.authority = new_authority;
+
+        Ok(())
+    }
+
+    // same as update_metadata_uri, for metadata held by an authority vault
+    pub fn vault_update_metadata_uri(
+        ctx: Context<VaultUpdateMetadataContext>,
+        uri: String,
+        content_hash: Option<[u8; 32]>,
+    ) -> ProgramResult {
+        let uri_changed = content_hash::uri_changed(&ctx.accounts.metadata, &uri);
+        let seeds = ctx.accounts.vault.signer_seeds();
+
+        instructions::update_metadata::update_uri(
+            ctx.accounts
+                .into_update_metadata_context()
+                .with_signer(&[&seeds[..]]),
+            uri,
+            content_hash,
+        )?;
+        history::record(
+            ctx.remaining_accounts,
+            &ctx.accounts.metadata,
+            ctx.accounts.authority.key(),
+        )?;
+        content_hash::record(
+            ctx.remaining_accounts,
+            &ctx.accounts.metadata.mint,
+            content_hash,
+            uri_changed,
+        )?;
+
+        Ok(())
+    }
+
+    // same as update_metadata, for metadata held by an authority vault
+    pub fn vault_update_metadata(
+        ctx: Context<VaultUpdateMetadataContext>,
+        data: UpdateMetadataInfo,
+    ) -> ProgramResult {
+        let hash = data.content_hash;
+        let uri_changed = content_hash::uri_changed(&ctx.accounts.metadata, &data.uri);
+        let seeds = ctx.accounts.vault.signer_seeds();
+
+        instructions::update_metadata::update_data(
+            ctx.accounts
+                .into_update_metadata_context()
+                .with_signer(&[&seeds[..]]),
+            data,
+        )?;
+        history::record(
+            ctx.remaining_accounts,
+            &ctx.accounts.metadata,
+            ctx.accounts.authority.key(),
+        )?;
+        content_hash::record(
+            ctx.remaining_accounts,
+            &ctx.accounts.metadata.mint,
+            hash,
+            uri_changed,
+        )?;
+
+        Ok(())
+    }
+
+    // same as patch_metadata, for metadata held by an authority vault
+    pub fn vault_patch_metadata(
+        ctx: Context<VaultUpdateMetadataContext>,
+        patch: PatchMetadataInfo,
+    ) -> ProgramResult {
+        let hash = patch.content_hash;
+        let uri_changed = patch.uri.as_ref().map_or(false, |uri| {
+            content_hash::uri_changed(&ctx.accounts.metadata, uri)
+        });
+        let seeds = ctx.accounts.vault.signer_seeds();
+
+        instructions::update_metadata::patch_data(
+            ctx.accounts
+                .into_update_metadata_context()
+                .with_signer(&[&seeds[..]]),
+            patch,
+        )?;
+        history::record(
+            ctx.remaining_accounts,
+            &ctx.accounts.metadata,
+            ctx.accounts.authority.key(),
+        )?;
+        content_hash::record(
+            ctx.remaining_accounts,
+            &ctx.accounts.metadata.mint,
+            hash,
+            uri_changed,
+        )?;
+
+        Ok(())
+    }
+
+    // moves metadata out of the vault, handing update authority back to a regular account
+    pub fn vault_release_update_authority(
+        ctx: Context<VaultUpdateMetadataContext>,
+        new_update_authority: Pubkey,
+    ) -> ProgramResult {
+        let seeds = ctx.accounts.vault.signer_seeds();
+
+        instructions::update_metadata::update_authority(
+            ctx.accounts
+                .into_update_metadata_context()
+                .with_signer(&[&seeds[..]]),
+            new_update_authority,
+        )?;
+        history::record(
+            ctx.remaining_accounts,
+            &ctx.accounts.metadata,
+            ctx.accounts.authority.key(),
+        )?;
+
+        Ok(())
+    }
+
+    // same as verify_collection, for a collection nft held by an authority vault
+    pub fn vault_verify_collection(ctx: Context<VaultVerifyCollectionContext>) -> ProgramResult {
+        let seeds = ctx.accounts.vault.signer_seeds();
+
+        instructions::verify_collection::verify(
+            ctx.accounts
+                .into_verify_collection_context()
+                .with_signer(&[&seeds[..]]),
+        )?;
+
+        Ok(())
+    }
+
+    // same as unverify_collection, for a collection nft held by an authority vault
+    pub fn vault_unverify_collection(ctx: Context<VaultVerifyCollectionContext>) -> ProgramResult {
+        let seeds = ctx.accounts.vault.signer_seeds();
+
+        instructions::verify_collection::unverify(
+            ctx.accounts
+                .into_verify_collection_context()
+                .with_signer(&[&seeds[..]]),
+        )?;
+
+        Ok(())
+    }
+
+    // creates a multisig that can hold update authority of metadata. changes to such metadata go
+    // through propose_multisig_update, approve_multisig_update and execute_multisig_update.
+    pub fn create_multisig(
+        ctx: Context<CreateMultisigContext>,
+        owners: Vec<Pubkey>,
+        threshold: u8,
+        bump: u8,
+    ) -> ProgramResult {
+        if owners.is_empty() || owners.len() > Multisig::MAX_OWNERS {
+            return Err(ErrorCode::InvalidMultisigOwners.into());
+        }
+        for (i, owner) in owners.iter().enumerate() {
+            if owners[..i].contains(owner) {
+                return Err(ErrorCode::InvalidMultisigOwners.into());
+            }
+        }
+        if threshold == 0 || threshold as usize > owners.len() {
+            return Err(ErrorCode::InvalidMultisigThreshold.into());
+        }
+
+        let multisig = &mut ctx.accounts.multisig;
+        multisig.base = ctx.accounts.base.key();
+        multisig.owners = owners;
+        multisig.threshold = threshold;
+        multisig.proposal_count = 0;
+        multisig.bump = bump;
+
+        Ok(())
+    }
+
+    // any owner can propose a metadata change; the proposer's approval is counted right away. an
+    // update authority change moves the metadata out of the multisig.
+    pub fn propose_multisig_update(
+        ctx: Context<ProposeMultisigUpdateContext>,
+        change: QueuedMetadataChange,
+    ) -> ProgramResult {
+        let multisig = &mut ctx.accounts.multisig;
+        let owner_index = multisig
+            .owner_index(&ctx.accounts.proposer.key())
+            .ok_or(ErrorCode::NotMultisigOwner)?;
+
+        let proposal = &mut ctx.accounts.proposal;
+        proposal.multisig = multisig.key();
+        proposal.metadata = ctx.accounts.metadata.key();
+        proposal.proposer = ctx.accounts.proposer.key();
+        proposal.index = multisig.proposal_count;
+        proposal.change = change;
+        proposal.approvals = vec![false; multisig.owners.len()];
+        proposal.approvals[owner_index] = true;
+
+        multisig.proposal_count += 1;
+
+        emit!(MultisigUpdateProposed {
+            multisig: multisig.key(),
+            proposal: proposal.key(),
+            metadata: proposal.metadata,
+            proposer: proposal.proposer,
+        });
+
+        Ok(())
+    }
+
+    // owner of the multisig approves a pending proposal
+    pub fn approve_multisig_update(ctx: Context<ApproveMultisigUpdateContext>) -> ProgramResult {
+        let multisig = &ctx.accounts.multisig;
+        let owner_index = multisig
+            .owner_index(&ctx.accounts.owner.key())
+            .ok_or(ErrorCode::NotMultisigOwner)?;
+
+        let proposal = &mut ctx.accounts.proposal;
+        if proposal.approvals[owner_index] {
+            return Err(ErrorCode::ProposalAlreadyApproved.into());
+        }
+        proposal.approvals[owner_index] = true;
+
+        emit!(MultisigUpdateApproved {
+            multisig: multisig.key(),
+            proposal: proposal.key(),
+            owner: ctx.accounts.owner.key(),
+            approvals: proposal.approval_count() as u8,
+            threshold: multisig.threshold,
+        });
+
+        Ok(())
+    }
+
+    // anyone can execute a proposal once enough owners approved it. the multisig signs the update
+    // and the proposal is closed, returning its rent to the proposer.
+    pub fn execute_multisig_update<'info>(
+        ctx: Context<'_, '_, '_, 'info, ExecuteMultisigUpdateContext<'info>>,
+    ) -> ProgramResult {
+        if ctx.accounts.proposal.approval_count() < ctx.accounts.multisig.threshold as usize {
+            return Err(ErrorCode::ProposalThresholdNotReached.into());
+        }
+
+        let seeds = ctx.accounts.multisig.signer_seeds();
+
+        instructions::update_metadata::apply_change(
+            ctx.accounts
+                .into_update_metadata_context()
+                .with_signer(&[&seeds[..]]),
+            ctx.remaining_accounts,
+            ctx.accounts.proposal.change.clone(),
+            ctx.accounts.proposal.proposer,
+        )?;
+
+        emit!(MultisigUpdateExecuted {
+            multisig: ctx.accounts.multisig.key(),
+            proposal: ctx.accounts.proposal.key(),
+            metadata: ctx.accounts.metadata.key(),
+        });
+
+        Ok(())
+    }
+
+    // creates the timelock for a collection. metadata whose update authority is the timelock can
+    // only be changed by queueing the change and waiting out the delay (in seconds). only the update
+    // authority of the collection nft can create its timelock, and starts out as its authority.
+    pub fn initialize_timelock(
+        ctx: Context<InitializeTimelockContext>,
+        collection: Pubkey,
+        delay: i64,
+        bump: u8,
+    ) -> ProgramResult {
+        if delay <= 0 {
+            return Err(ErrorCode::InvalidTimelockDelay.into());
+        }
+
+        let timelock = &mut ctx.accounts.timelock;
+        timelock.collection = collection;
+        timelock.authority = ctx.accounts.authority.key();
+        timelock.delay = delay;
+        timelock.bump = bump;
+
+        Ok(())
+    }
+
+    // timelock authority can increase the delay; updates that are already queued keep their time.
+    // the delay can't be lowered, otherwise the authority could skip the wait for its next change.
+    pub fn set_timelock_delay(ctx: Context<SetTimelockDelayContext>, delay: i64) -> ProgramResult {
+        let timelock = &mut ctx.accounts.timelock;
+        if delay < timelock.delay {
+            return Err(ErrorCode::TimelockDelayDecreased.into());
+        }
+
+        emit!(TimelockDelayUpdated {
+            timelock: timelock.key(),
+            collection: timelock.collection,
+            old_delay: timelock.delay,
+            new_delay: delay,
+        });
+
+        timelock.delay = delay;
+
+        Ok(())
+    }
+
+    // timelock authority queues a change; it becomes executable once the delay has passed
+    pub fn queue_metadata_update(
+        ctx: Context<QueueMetadataUpdateContext>,
+        change: QueuedMetadataChange,
+    ) -> ProgramResult {
+        let now = Clock::get()?.unix_timestamp;
+        let executable_at = now
+            .checked_add(ctx.accounts.timelock.delay)
+            .ok_or(ErrorCode::InvalidTimelockDelay)?;
+
+        let queued_update = &mut ctx.accounts.queued_update;
+        queued_update.timelock = ctx.accounts.timelock.key();
+        queued_update.metadata = ctx.accounts.metadata.key();
+        queued_update.authority = ctx.accounts.authority.key();
+        queued_update.change = change.clone();
+        queued_update.queued_at = now;
+        queued_update.executable_at = executable_at;
+
+        emit!(MetadataUpdateQueued {
+            mint: ctx.accounts.metadata.mint,
+            metadata: ctx.accounts.metadata.key(),
+            queued_update: queued_update.key(),
+            change,
+            executable_at,
+        });
+
+        Ok(())
+    }
+
+    // crank; anyone can apply a queued change once it's executable. the timelock signs the update
+    // and the queued update is closed, returning its rent to the authority that queued it.
+    pub fn execute_metadata_update<'info>(
+        ctx: Context<'_, '_, '_, 'info, ExecuteMetadataUpdateContext<'info>>,
+    ) -> ProgramResult {
+        if Clock::get()?.unix_timestamp < ctx.accounts.queued_update.executable_at {
+            return Err(ErrorCode::TimelockNotExpired.into());
+        }
+
+        let seeds = ctx.accounts.timelock.signer_seeds();
+
+        instructions::update_metadata::apply_change(
+            ctx.accounts
+                .into_update_metadata_context()
+                .with_signer(&[&seeds[..]]),
+            ctx.remaining_accounts,
+            ctx.accounts.queued_update.change.clone(),
+            ctx.accounts.queued_update.authority,
+        )?;
+
+        Ok(())
+    }
+
+    // timelock authority can drop a queued change at any time before it's executed
+    pub fn cancel_metadata_update(ctx: Context<CancelMetadataUpdateContext>) -> ProgramResult {
+        emit!(MetadataUpdateCancelled {
+            metadata: ctx.accounts.queued_update.metadata,
+            queued_update: ctx.accounts.queued_update.key(),
+        });
+
+        Ok(())
+    }
+
+    // anyone can opt a mint into on-chain revision history. afterwards, every update instruction
+    // records the previous state of the metadata in the history account of the mint.
+    pub fn initialize_metadata_history(
+        ctx: Context<InitializeMetadataHistoryContext>,
+    ) -> ProgramResult {
+        let history = &mut ctx.accounts.history;
+        history.mint = ctx.accounts.metadata.mint;
+        history.revision_count = 0;
+        history.revisions = vec![];
+
+        Ok(())
+    }
+
+    // creates the global config; the signer has to be the upgrade authority of the program, and
+    // becomes admin. meant to be called right after deploying the program, since until then
+    // nothing can run.
+    pub fn initialize_config(
+        ctx: Context<InitializeConfigContext>,
+        info: InitializeConfigInfo,
+        bump: u8,
+    ) -> ProgramResult {
+        if info.fee_waivers.len() > Config::MAX_FEE_WAIVERS {
+            return Err(ErrorCode::TooManyFeeWaivers.into());
+        }
+
+        let config = &mut ctx.accounts.config;
+        config.admin = ctx.accounts.admin.key();
+        config.paused = false;
+        config.treasury = info.treasury;
+        config.generate_fee_lamports = info.generate_fee_lamports;
+        config.fee_mint = info.fee_mint;
+        config.generate_fee_tokens = info.generate_fee_tokens;
+        config.fee_waivers = info.fee_waivers;
+        config.bump = bump;
+
+        Ok(())
+    }
+
+    // admin can change any config field, including pausing every other instruction in an emergency
+    pub fn update_config(
+        ctx: Context<UpdateConfigContext>,
+        info: UpdateConfigInfo,
+    ) -> ProgramResult {
+        let config = &mut ctx.accounts.config;
+        config.admin = info.admin.unwrap_or(config.admin);
+        config.paused = info.paused.unwrap_or(config.paused);
+        config.treasury = info.treasury.unwrap_or(config.treasury);
+        config.generate_fee_lamports = info
+            .generate_fee_lamports
+            .unwrap_or(config.generate_fee_lamports);
+        config.fee_mint = info.fee_mint.unwrap_or(config.fee_mint);
+        config.generate_fee_tokens = info
+            .generate_fee_tokens
+            .unwrap_or(config.generate_fee_tokens);
+        if let Some(fee_waivers) = info.fee_waivers {
+            if fee_waivers.len() > Config::MAX_FEE_WAIVERS {
+                return Err(ErrorCode::TooManyFeeWaivers.into());
+            }
+            config.fee_waivers = fee_waivers;
+        }
+
+        emit!(ConfigUpdated {
+            admin: config.admin,
+            paused: config.paused,
+            treasury: config.treasury,
+            generate_fee_lamports: config.generate_fee_lamports,
+            fee_mint: config.fee_mint,
+            generate_fee_tokens: config.generate_fee_tokens,
+            fee_waivers: config.fee_waivers.clone(),
+        });
+
+        Ok(())
+    }
+
+    // collection update authority stores the metadata defaults of a collection, so that generate
+    // calls only need to send what differs per nft
+    pub fn initialize_collection_template(
+        ctx: Context<InitializeCollectionTemplateContext>,
+        collection: Pubkey,
+        template: MetadataTemplate,
+        bump: u8,
+    ) -> ProgramResult {
+        validation::validate_template(&template)?;
+
+        let collection_template = &mut ctx.accounts.template;
+        collection_template.collection = collection;
+        collection_template.authority = ctx.accounts.authority.key();
+        collection_template.template = template;
+        collection_template.bump = bump;
+
+        Ok(())
+    }
+
+    // template authority can replace the defaults; nfts generated before keep their metadata
+    pub fn update_collection_template(
+        ctx: Context<UpdateCollectionTemplateContext>,
+        template: MetadataTemplate,
+    ) -> ProgramResult {
+        validation::validate_template(&template)?;
+
+        ctx.accounts.template.template = template;
+
+        Ok(())
+    }
+
+    // same as generate, but every field except name and uri comes from the collection template
+    // unless it's overridden
+    pub fn generate_from_template<'info>(
+        ctx: Context<'_, '_, '_, 'info, GenerateFromTemplateContext<'info>>,
+        name: String,
+        uri: String,
+        overrides: TemplateOverrides,
+        fee_payment: FeePayment,
+        content_hash: Option<[u8; 32]>,
+    ) -> ProgramResult {
+        let mut metadata_info = ctx.accounts.template.to_metadata_info(name, uri, overrides);
+        metadata_info.content_hash = content_hash;
+        instructions::generate::process(
+            &ctx.accounts.generate,
+            ctx.remaining_accounts,
+            metadata_info,
+            fee_payment,
+        )?;
+
+        Ok(())
+    }
+
+    // collection update authority sets up numbering for a collection, with a max supply. items are
+    // named "name_prefix #N" with uri "base_uri/N.json", counting from 1.
+    pub fn initialize_collection_counter(
+        ctx: Context<InitializeCollectionCounterContext>,
+        collection: Pubkey,
+        max_supply: u64,
+        name_prefix: String,
+        base_uri: String,
+        bump: u8,
+    ) -> ProgramResult {
+        validation::validate_numbering(&name_prefix, &base_uri, max_supply)?;
+
+        let counter = &mut ctx.accounts.counter;
+        counter.collection = collection;
+        counter.authority = ctx.accounts.authority.key();
+        counter.count = 0;
+        counter.max_supply = max_supply;
+        counter.name_prefix = name_prefix;
+        counter.base_uri = base_uri;
+        counter.bump = bump;
+
+        Ok(())
+    }
+
+    // same as generate, but name and uri are numbered by the collection counter, and the metadata
+    // has to be part of the counter's collection. fails once the counter's max supply is reached.
+    pub fn generate_numbered<'info>(
+        ctx: Context<'_, '_, '_, 'info, GenerateNumberedContext<'info>>,
+        template: MetadataTemplate,
+        fee_payment: FeePayment,
+        content_hash: Option<[u8; 32]>,
+    ) -> ProgramResult {
+        let counter = &mut ctx.accounts.counter;
+        if template.collection.as_ref().map(|c| c.key) != Some(counter.collection) {
+            return Err(ErrorCode::MetadataCollectionMismatch.into());
+        }
+
+        let number = counter.next_number()?;
+        let mut metadata_info = template.to_metadata_info(
+            numbered_name(&counter.name_prefix, number),
+            numbered_uri(&counter.base_uri, number),
+        );
+        metadata_info.content_hash = content_hash;
+        instructions::generate::process(
+            &ctx.accounts.generate,
+            ctx.remaining_accounts,
+            metadata_info,
+            fee_payment,
+        )?;
+
+        Ok(())
+    }
+
+    // commits to the final uris before launch, either per mint (root is the leaf of that mint) or
+    // per collection (root of a merkle tree over the leaves of every mint), where each leaf is the
+    // leaf hash of a mint and its uri, followed by the content hash of its json if there is one
+    // (see merkle::hash_leaf).
+    // metadata handed to the commitment can only have its uri revealed, and only the update
+    // authority of the mint or collection nft can commit.
+    pub fn commit_reveal(
+        ctx: Context<CommitRevealContext>,
+        key: Pubkey,
+        root: [u8; 32],
+        reveal_at: i64,
+        bump: u8,
+    ) -> ProgramResult {
+        let commitment = &mut ctx.accounts.commitment;
+        commitment.key = key;
+        commitment.authority = ctx.accounts.authority.key();
+        commitment.root = root;
+        commitment.reveal_at = reveal_at;
+        commitment.bump = bump;
+
+        Ok(())
+    }
+
+    // sets the committed uri on the metadata once the proof checks out. the commitment authority
+    // can reveal at any time; after reveal_at anyone can crank it.
+    pub fn reveal(
+        ctx: Context<RevealContext>,
+        uri: String,
+        content_hash: Option<[u8; 32]>,
+        proof: Vec<[u8; 32]>,
+    ) -> ProgramResult {
+        let commitment = &ctx.accounts.commitment;
+        if ctx.accounts.cranker.key() != commitment.authority
+            && Clock::get()?.unix_timestamp < commitment.reveal_at
+        {
+            return Err(ErrorCode::RevealNotStarted.into());
+        }
+
+        let leaf = RevealCommitment::leaf(&ctx.accounts.metadata.mint, &uri, content_hash);
+        if !merkle::verify_proof(&proof, commitment.root, leaf) {
+            return Err(ErrorCode::InvalidRevealProof.into());
+        }
+
+        let seeds = commitment.signer_seeds();
+        let uri_changed = content_hash::uri_changed(&ctx.accounts.metadata, &uri);
+
+        instructions::update_metadata::update_uri(
+            ctx.accounts
+                .into_update_metadata_context()
+                .with_signer(&[&seeds[..]]),
+            uri,
+            content_hash,
+        )?;
+        history::record(
+            ctx.remaining_accounts,
+            &ctx.accounts.metadata,
+            ctx.accounts.cranker.key(),
+        )?;
+        content_hash::record(
+            ctx.remaining_accounts,
+            &ctx.accounts.metadata.mint,
+            content_hash,
+            uri_changed,
+        )?;
+
+        Ok(())
+    }
+
+    // commitment authority hands update authority of revealed metadata back to a regular account.
+    // the proof has to show that the current uri is the committed one, so that metadata can't be
+    // released before it's revealed. content_hash is the one committed with the uri, if any.
+    pub fn release_reveal_authority(
+        ctx: Context<ReleaseRevealAuthorityContext>,
+        new_update_authority: Pubkey,
+        content_hash: Option<[u8; 32]>,
+        proof: Vec<[u8; 32]>,
+    ) -> ProgramResult {
+        let metadata = &ctx.accounts.metadata;
+        let leaf = RevealCommitment::leaf(
+            &metadata.mint,
+            metadata.data.uri.trim_matches(char::from(0)),
+            content_hash,
+        );
+        if !merkle::verify_proof(&proof, ctx.accounts.commitment.root, leaf) {
+            return Err(ErrorCode::MetadataNotRevealed.into());
+        }
+
+        let seeds = ctx.accounts.commitment.signer_seeds();
+
+        instructions::update_metadata::update_authority(
+            ctx.accounts
+                .into_update_metadata_context()
+                .with_signer(&[&seeds[..]]),
+            new_update_authority,
+        )?;
+        history::record(
+            ctx.remaining_accounts,
+            &ctx.accounts.metadata,
+            ctx.accounts.authority.key(),
+        )?;
+
+        Ok(())
+    }
+
+    // collection update authority sets the merkle root of the wallets allowed to mint into a
+    // collection. leaves are merkle::hash_leaf(wallet, max_mints), where max_mints = 0 means no
+    // limit.
+    pub fn initialize_allowlist(
+        ctx: Context<InitializeAllowlistContext>,
+        collection: Pubkey,
+        root: [u8; 32],
+        bump: u8,
+    ) -> ProgramResult {
+        let allowlist = &mut ctx.accounts.allowlist;
+        allowlist.collection = collection;
+        allowlist.authority = ctx.accounts.authority.key();
+        allowlist.root = root;
+        allowlist.bump = bump;
+
+        Ok(())
+    }
+
+    // allowlist authority can replace the root; mints already counted against a wallet stay counted
+    pub fn update_allowlist_root(
+        ctx: Context<UpdateAllowlistRootContext>,
+        root: [u8; 32],
+    ) -> ProgramResult {
+        ctx.accounts.allowlist.root = root;
+
+        Ok(())
+    }
+
+    // same as generate_with_mint, but the payer has to prove it's on the collection's allowlist,
+    // and receives the nft. the proof and the per wallet limit are checked before any cpi.
+    //
+    // the allowlist pda signs as mint and update authority, so claimers don't need a co-signer.
+    // like a drop, the master edition takes over the mint authority, which is why the supply can't
+    // be none, and update authority passes on to the allowlist authority. generate fee waivers
+    // apply to the allowlist authority.
+    pub fn generate_allowlisted<'info>(
+        ctx: Context<'_, '_, '_, 'info, GenerateAllowlistedContext<'info>>,
+        metadata_info: MetadataInfo,
+        max_mints: u64,
+        proof: Vec<[u8; 32]>,
+        fee_payment: FeePayment,
+    ) -> ProgramResult {
+        let allowlist = &ctx.accounts.allowlist;
+        let leaf = Allowlist::leaf(&ctx.accounts.payer.key(), max_mints);
+        if !merkle::verify_proof(&proof, allowlist.root, leaf) {
+            return Err(ErrorCode::NotAllowlisted.into());
+        }
+        if metadata_info.collection.as_ref().map(|c| c.key) != Some(allowlist.collection) {
+            return Err(ErrorCode::MetadataCollectionMismatch.into());
+        }
+        if metadata_info.supply == MasterEditionSupply::None {
+            return Err(ErrorCode::AllowlistRequiresMasterEdition.into());
+        }
+        let metadata_info = MetadataInfo {
+            new_update_authority: Some(allowlist.authority),
+            ..metadata_info
+        };
+
+        let claim_status = &mut ctx.accounts.claim_status;
+        if max_mints > 0 && claim_status.minted >= max_mints {
+            return Err(ErrorCode::AllowlistLimitReached.into());
+        }
+        claim_status.allowlist = ctx.accounts.allowlist.key();
+        claim_status.claimant = ctx.accounts.payer.key();
+        claim_status.minted += 1;
+
+        let allowlist = &ctx.accounts.allowlist;
+        let config = &ctx.accounts.unpaused.config;
+        match fee_payment {
+            FeePayment::Lamports => instructions::collect_fee::handle(
+                ctx.accounts.into_collect_fee_context(),
+                ctx.accounts.mint.key(),
+                config.generate_fee_lamports_for(&allowlist.authority),
+            )?,
+            FeePayment::Token => instructions::collect_fee::handle_token(
+                ctx.accounts
+                    .into_collect_token_fee_context(ctx.remaining_accounts)?,
+                ctx.accounts.mint.key(),
+                config.fee_mint.ok_or(ErrorCode::FeeMintNotSet)?,
+                config.generate_fee_tokens_for(&allowlist.authority),
+            )?,
+        };
+
+        let hash = metadata_info.content_hash;
+        let seeds = allowlist.signer_seeds();
+        let signer = &[&seeds[..]];
+        anchor_spl::token::mint_to(ctx.accounts.into_mint_to_context().with_signer(signer), 1)?;
+        instructions::generate::handle(
+            ctx.accounts
+                .into_create_metadata_context()
+                .with_signer(signer),
+            ctx.accounts
+                .into_create_master_edition_metadata_context()
+                .with_signer(signer),
+            ctx.accounts
+                .into_update_metadata_context()
+                .with_signer(signer),
+            metadata_info,
+        )?;
+
+        content_hash::record(
+            ctx.remaining_accounts,
+            &ctx.accounts.mint.key(),
+            hash,
+            false,
+        )?;
+
+        Ok(())
+    }
+
+    // buys an nft from a drop; checks the sale window and supply, pays the price into the drop's
+    // treasury, then mints the next item to the buyer like generate_with_mint. name and uri are
+    // numbered by the drop, everything else comes from the collection template.
+    //
+    // the drop pda signs as mint and update authority, so buyers don't need a co-signer. the
+    // master edition takes over the mint authority, which is why the template supply can't be
+    // none, and update authority passes on to the template's new update authority, or otherwise
+    // the drop authority. generate fee waivers apply to the drop authority. items are minted without
+    // a content hash, as it would come from the buyer; their update authority can store one later.
+    pub fn mint_from_drop<'info>(
+        ctx: Context<'_, '_, '_, 'info, MintFromDropContext<'info>>,
+        fee_payment: FeePayment,
+    ) -> ProgramResult {
+        let now = Clock::get()?.unix_timestamp;
+        let drop = &mut ctx.accounts.drop;
+        if now < drop.go_live_at {
+            return Err(ErrorCode::DropNotStarted.into());
+        }
+        if now >= drop.end_at {
+            return Err(ErrorCode::DropEnded.into());
+        }
+        if drop.items_minted >= drop.max_items {
+            return Err(ErrorCode::DropSoldOut.into());
+        }
+        drop.items_minted += 1;
+
+        let mut metadata_info = ctx.accounts.template.to_metadata_info(
+            numbered_name(&drop.name_prefix, drop.items_minted),
+            numbered_uri(&drop.base_uri, drop.items_minted),
+            TemplateOverrides::default(),
+        );
+        if metadata_info.supply == MasterEditionSupply::None {
+            return Err(ErrorCode::DropRequiresMasterEdition.into());
+        }
+        metadata_info.new_update_authority =
+            metadata_info.new_update_authority.or(Some(drop.authority));
+
+        instructions::collect_fee::pay(
+            ctx.accounts.into_pay_drop_context(),
+            ctx.accounts.drop.price_lamports,
+        )?;
+
+        emit!(DropMinted {
+            drop: ctx.accounts.drop.key(),
+            mint: ctx.accounts.mint.key(),
+            buyer: ctx.accounts.payer.key(),
+            price_lamports: ctx.accounts.drop.price_lamports,
+            items_minted: ctx.accounts.drop.items_minted,
+        });
+
+        let drop = &ctx.accounts.drop;
+        let config = &ctx.accounts.unpaused.config;
+        match fee_payment {
+            FeePayment::Lamports => instructions::collect_fee::handle(
+                ctx.accounts.into_collect_fee_context(),
+                ctx.accounts.mint.key(),
+                config.generate_fee_lamports_for(&drop.authority),
+            )?,
+            FeePayment::Token => instructions::collect_fee::handle_token(
+                ctx.accounts
+                    .into_collect_token_fee_context(ctx.remaining_accounts)?,
+                ctx.accounts.mint.key(),
+                config.fee_mint.ok_or(ErrorCode::FeeMintNotSet)?,
+                config.generate_fee_tokens_for(&drop.authority),
+            )?,
+        };
+
+        let seeds = drop.signer_seeds();
+        let signer = &[&seeds[..]];
+        anchor_spl::token::mint_to(ctx.accounts.into_mint_to_context().with_signer(signer), 1)?;
+        instructions::generate::handle(
+            ctx.accounts
+                .into_create_metadata_context()
+                .with_signer(signer),
+            ctx.accounts
+                .into_create_master_edition_metadata_context()
+                .with_signer(signer),
+            ctx.accounts
+                .into_update_metadata_context()
+                .with_signer(signer),
+            metadata_info,
+        )?;
+
+        Ok(())
+    }
+
+    // anyone can create the content hash account of a mint, even before the mint exists, so a hash
+    // can be given to generate_with_mint. afterwards, generate and update instructions that are
+    // given a content hash store it there, and updates that change the uri without one clear it;
+    // the account is expected as a remaining account.
+    pub fn initialize_content_hash(ctx: Context<InitializeContentHashContext>) -> ProgramResult {
+        let content_hash = &mut ctx.accounts.content_hash;
+        content_hash.mint = ctx.accounts.mint.key();
+        content_hash.hash = None;
+        content_hash.slot = 0;
 
         Ok(())
     }
@@ -111,8 +1246,10 @@ pub struct MetadataInfo {
     pub is_mutable: bool,
     pub collection: Option<Collection>,
     pub uses: Option<Uses>,
-    pub supply: Option<u64>,
+    pub supply: MasterEditionSupply,
     pub new_update_authority: Option<Pubkey>,
+    // sha-256 of the json document at uri, see initialize_content_hash
+    pub content_hash: Option<[u8; 32]>,
 }
 
 impl MetadataInfo {
@@ -165,6 +1302,18 @@ fn to_mpl_use_method(use_method: &UseMethod) -> mpl_token_metadata::state::UseMe
     };
 }
 
+// whether generate creates a master edition, and if so how many editions can be printed from it
+#[repr(C)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default, PartialEq, Debug)]
+pub enum MasterEditionSupply {
+    #[default]
+    None,
+    Limited {
+        max_supply: u64,
+    },
+    Unlimited,
+}
+
 #[repr(C)]
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Debug)]
 pub enum UseMethod {
@@ -223,6 +1372,64 @@ pub struct DataV2 {
     pub uses: Option<Uses>,
 }
 
+impl DataV2 {
+    // snapshot of the data currently stored on metadata. metaplex pads name, symbol and uri with
+    // null bytes, which are stripped here.
+    pub fn from_mpl_metadata(metadata: &Metadata) -> Self {
+        let mut data = DataV2::from_mpl(&mpl_token_metadata::state::DataV2 {
+            name: metadata.data.name.clone(),
+            symbol: metadata.data.symbol.clone(),
+            uri: metadata.data.uri.clone(),
+            seller_fee_basis_points: metadata.data.seller_fee_basis_points,
+            creators: metadata.data.creators.clone(),
+            collection: metadata.collection.clone(),
+            uses: metadata.uses.clone(),
+        });
+
+        data.name = data.name.trim_matches(char::from(0)).to_string();
+        data.symbol = data.symbol.trim_matches(char::from(0)).to_string();
+        data.uri = data.uri.trim_matches(char::from(0)).to_string();
+
+        data
+    }
+
+    pub fn from_mpl(data: &mpl_token_metadata::state::DataV2) -> Self {
+        DataV2 {
+            name: data.name.clone(),
+            symbol: data.symbol.clone(),
+            uri: data.uri.clone(),
+            seller_fee_basis_points: data.seller_fee_basis_points,
+            creators: data.creators.as_ref().map(|creators| {
+                creators
+                    .iter()
+                    .map(|creator| Creator {
+                        address: creator.address,
+                        verified: creator.verified,
+                        share: creator.share,
+                    })
+                    .collect()
+            }),
+            collection: data.collection.as_ref().map(|collection| Collection {
+                verified: collection.verified,
+                key: collection.key,
+            }),
+            uses: data.uses.as_ref().map(|uses| Uses {
+                use_method: from_mpl_use_method(&uses.use_method),
+                remaining: uses.remaining,
+                total: uses.total,
+            }),
+        }
+    }
+}
+
+fn from_mpl_use_method(use_method: &mpl_token_metadata::state::UseMethod) -> UseMethod {
+    match use_method {
+        mpl_token_metadata::state::UseMethod::Burn => UseMethod::Burn,
+        mpl_token_metadata::state::UseMethod::Multiple => UseMethod::Multiple,
+        mpl_token_metadata::state::UseMethod::Single => UseMethod::Single,
+    }
+}
+
 // =================================================================================================
 // custom struct to wrap mpl_token_metadata Metadata so that we can use in anchor context. can also
 // be used by any downstream clients using this code.
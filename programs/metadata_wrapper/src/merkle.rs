@@ -0,0 +1,76 @@
+use anchor_lang::solana_program::hash::hashv;
+
+// leaves and nodes are hashed with distinct prefixes, so that the data of a leaf can't be made to
+// hash like a pair of nodes, or the other way around
+pub const LEAF_PREFIX: &[u8] = &[0];
+pub const NODE_PREFIX: &[u8] = &[1];
+
+// leaf of the given parts, see Allowlist::leaf and RevealCommitment::leaf
+pub fn hash_leaf(parts: &[&[u8]]) -> [u8; 32] {
+    hashv(&[&[LEAF_PREFIX], parts].concat()).to_bytes()
+}
+
+// parent of two nodes; pairs are hashed in sorted order
+fn hash_node(a: &[u8; 32], b: &[u8; 32]) -> [u8; 32] {
+    match a <= b {
+        true => hashv(&[NODE_PREFIX, a, b]).to_bytes(),
+        false => hashv(&[NODE_PREFIX, b, a]).to_bytes(),
+    }
+}
+
+// verifies a merkle proof for a leaf. pairs are hashed in sorted order, so proofs don't need to
+// encode whether a node is a left or right sibling. a tree with a single leaf has that leaf as root
+// and an empty proof.
+pub fn verify_proof(proof: &[[u8; 32]], root: [u8; 32], leaf: [u8; 32]) -> bool {
+    let mut computed = leaf;
+    for node in proof.iter() {
+        computed = hash_node(&computed, node);
+    }
+
+    computed == root
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn single_leaf_is_its_own_root() {
+        let leaf = [1; 32];
+
+        assert!(verify_proof(&[], leaf, leaf));
+        assert!(!verify_proof(&[], leaf, [2; 32]));
+    }
+
+    #[test]
+    fn verifies_every_leaf_of_a_tree() {
+        let leaves = [[1; 32], [2; 32], [3; 32], [4; 32]];
+        let left = hash_node(&leaves[0], &leaves[1]);
+        let right = hash_node(&leaves[2], &leaves[3]);
+        let root = hash_node(&left, &right);
+
+        assert!(verify_proof(&[leaves[1], right], root, leaves[0]));
+        assert!(verify_proof(&[leaves[0], right], root, leaves[1]));
+        assert!(verify_proof(&[leaves[3], left], root, leaves[2]));
+        assert!(verify_proof(&[leaves[2], left], root, leaves[3]));
+    }
+
+    #[test]
+    fn rejects_wrong_leaf_or_proof() {
+        let leaves = [[1; 32], [2; 32], [3; 32], [4; 32]];
+        let left = hash_node(&leaves[0], &leaves[1]);
+        let right = hash_node(&leaves[2], &leaves[3]);
+        let root = hash_node(&left, &right);
+
+        assert!(!verify_proof(&[leaves[1], right], root, [5; 32]));
+        assert!(!verify_proof(&[leaves[2], right], root, leaves[0]));
+        assert!(!verify_proof(&[leaves[1]], root, leaves[0]));
+    }
+
+    #[test]
+    fn leaf_of_two_nodes_is_not_their_parent() {
+        let (a, b) = ([1; 32], [2; 32]);
+
+        assert_ne!(hash_leaf(&[&a, &b]), hash_node(&a, &b));
+    }
+}
@@ -0,0 +1,669 @@
+use {
+    crate::{
+        merkle, Collection, Creator, ErrorCode, MasterEditionSupply, MetadataInfo,
+        UpdateMetadataInfo, Uses,
+    },
+    anchor_lang::prelude::*,
+    mpl_token_metadata::state::{
+        MAX_CREATOR_LEN, MAX_CREATOR_LIMIT, MAX_NAME_LENGTH, MAX_SYMBOL_LENGTH, MAX_URI_LENGTH,
+    },
+};
+
+// upper bound of a serialized UpdateMetadataInfo; name, symbol and uri are length prefixed strings,
+// collection is (verified, key) and uses is (use_method, remaining, total)
+pub const MAX_UPDATE_METADATA_INFO_LEN: usize = 4
+    + MAX_NAME_LENGTH
+    + 4
+    + MAX_SYMBOL_LENGTH
+    + 4
+    + MAX_URI_LENGTH
+    + 2
+    + 1
+    + 4
+    + MAX_CREATOR_LIMIT * MAX_CREATOR_LEN
+    + 1
+    + 33
+    + 1
+    + 17
+    + 1
+    + 32;
+
+// update authority proposed for a metadata account. the current update authority hands the
+// metadata to this account when proposing, so that the proposed authority can accept on its own.
+// it's signed over with the account's seeds, to the proposed authority on accept or back to the
+// proposing one on cancel, and the account is closed then.
+#[account]
+#[derive(Default)]
+pub struct PendingUpdateAuthority {
+    pub metadata: Pubkey,
+    pub update_authority: Pubkey,
+    pub proposed_update_authority: Pubkey,
+    pub bump: u8,
+}
+
+impl PendingUpdateAuthority {
+    pub const SEED: &'static [u8] = b"pending_update_authority";
+    pub const LEN: usize = 8 + 32 + 32 + 32 + 1;
+
+    pub fn signer_seeds(&self) -> [&[u8]; 3] {
+        [
+            Self::SEED,
+            self.metadata.as_ref(),
+            std::slice::from_ref(&self.bump),
+        ]
+    }
+}
+
+// program owned update authority for every nft of a collection. metadata handed to the vault can
+// only be changed through the vault instructions, which are signed with the vault's seeds once the
+// vault authority has approved the change.
+#[account]
+#[derive(Default)]
+pub struct AuthorityVault {
+    pub collection: Pubkey,
+    pub authority: Pubkey,
+    pub bump: u8,
+}
+
+impl AuthorityVault {
+    pub const SEED: &'static [u8] = b"authority";
+    pub const LEN: usize = 8 + 32 + 32 + 1;
+
+    pub fn signer_seeds(&self) -> [&[u8]; 3] {
+        [
+            Self::SEED,
+            self.collection.as_ref(),
+            std::slice::from_ref(&self.bump),
+        ]
+    }
+}
+
+// set of owners that has to approve metadata changes. metadata handed to the multisig can only be
+// changed by executing a proposal once enough owners approved it; the cpi is signed with the
+// multisig's seeds.
+#[account]
+#[derive(Default)]
+pub struct Multisig {
+    pub base: Pubkey,
+    pub owners: Vec<Pubkey>,
+    pub threshold: u8,
+    pub proposal_count: u64,
+    pub bump: u8,
+}
+
+impl Multisig {
+    pub const SEED: &'static [u8] = b"multisig";
+    pub const MAX_OWNERS: usize = 10;
+    pub const LEN: usize = 8 + 32 + 4 + 32 * Self::MAX_OWNERS + 1 + 8 + 1;
+
+    pub fn owner_index(&self, owner: &Pubkey) -> Option<usize> {
+        self.owners.iter().position(|o| o == owner)
+    }
+
+    pub fn signer_seeds(&self) -> [&[u8]; 3] {
+        [
+            Self::SEED,
+            self.base.as_ref(),
+            std::slice::from_ref(&self.bump),
+        ]
+    }
+}
+
+// metadata change waiting for approval by the owners of a multisig. approvals are indexed the same
+// way as the multisig owners.
+#[account]
+pub struct MultisigProposal {
+    pub multisig: Pubkey,
+    pub metadata: Pubkey,
+    pub proposer: Pubkey,
+    pub index: u64,
+    pub change: QueuedMetadataChange,
+    pub approvals: Vec<bool>,
+}
+
+impl MultisigProposal {
+    pub const SEED: &'static [u8] = b"multisig_proposal";
+    pub const LEN: usize =
+        8 + 32 + 32 + 32 + 8 + 1 + MAX_UPDATE_METADATA_INFO_LEN + 4 + Multisig::MAX_OWNERS;
+
+    pub fn approval_count(&self) -> usize {
+        self.approvals.iter().filter(|approved| **approved).count()
+    }
+}
+
+// update authority for every nft of a collection that only applies changes after a delay. the
+// timelock authority queues changes, and anyone can execute them once the delay has passed.
+#[account]
+#[derive(Default)]
+pub struct Timelock {
+    pub collection: Pubkey,
+    pub authority: Pubkey,
+    pub delay: i64,
+    pub bump: u8,
+}
+
+impl Timelock {
+    pub const SEED: &'static [u8] = b"timelock";
+    pub const LEN: usize = 8 + 32 + 32 + 8 + 1;
+
+    pub fn signer_seeds(&self) -> [&[u8]; 3] {
+        [
+            Self::SEED,
+            self.collection.as_ref(),
+            std::slice::from_ref(&self.bump),
+        ]
+    }
+}
+
+// change applied by a timelock once its delay passed, or by a multisig once enough owners approved
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Debug)]
+pub enum QueuedMetadataChange {
+    Data(UpdateMetadataInfo),
+    // uri and the content hash of the json it points to, if any
+    Uri(String, Option<[u8; 32]>),
+    UpdateAuthority(Pubkey),
+}
+
+// change waiting for the timelock delay to pass; at most one per metadata
+#[account]
+pub struct QueuedMetadataUpdate {
+    pub timelock: Pubkey,
+    pub metadata: Pubkey,
+    pub authority: Pubkey,
+    pub change: QueuedMetadataChange,
+    pub queued_at: i64,
+    pub executable_at: i64,
+}
+
+impl QueuedMetadataUpdate {
+    pub const SEED: &'static [u8] = b"queued_metadata_update";
+    pub const LEN: usize = 8 + 32 + 32 + 32 + 1 + MAX_UPDATE_METADATA_INFO_LEN + 8 + 8;
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, PartialEq, Debug)]
+pub struct MetadataRevision {
+    pub revision: u64,
+    pub slot: u64,
+    pub actor: Pubkey,
+    // hash of name, symbol, uri and creators before the update
+    pub previous_hash: [u8; 32],
+}
+
+// ring buffer of the most recent metadata revisions of a mint. once full, the oldest revision is
+// overwritten; revision_count keeps counting so that gaps are visible.
+#[account]
+#[derive(Default)]
+pub struct MetadataHistory {
+    pub mint: Pubkey,
+    pub revision_count: u64,
+    pub revisions: Vec<MetadataRevision>,
+}
+
+impl MetadataHistory {
+    pub const SEED: &'static [u8] = b"history";
+    pub const CAPACITY: usize = 16;
+    pub const LEN: usize = 8 + 32 + 8 + 4 + (8 + 8 + 32 + 32) * Self::CAPACITY;
+
+    pub fn push(&mut self, revision: MetadataRevision) {
+        if self.revisions.len() < Self::CAPACITY {
+            self.revisions.push(revision);
+        } else {
+            let index = (self.revision_count % Self::CAPACITY as u64) as usize;
+            self.revisions[index] = revision;
+        }
+        self.revision_count += 1;
+    }
+}
+
+// global program settings, owned by the admin
+#[account]
+#[derive(Default)]
+pub struct Config {
+    pub admin: Pubkey,
+    pub paused: bool,
+    pub treasury: Pubkey,
+    pub generate_fee_lamports: u64,
+    // optional spl token fee; payers can pay it instead of the lamport fee
+    pub fee_mint: Option<Pubkey>,
+    pub generate_fee_tokens: u64,
+    // update authorities that don't pay the generate fee
+    pub fee_waivers: Vec<Pubkey>,
+    pub bump: u8,
+}
+
+impl Config {
+    pub const SEED: &'static [u8] = b"config";
+    pub const MAX_FEE_WAIVERS: usize = 16;
+    pub const LEN: usize = 8 + 32 + 1 + 32 + 8 + 1 + 32 + 8 + 4 + 32 * Self::MAX_FEE_WAIVERS + 1;
+
+    pub fn generate_fee_lamports_for(&self, update_authority: &Pubkey) -> u64 {
+        match self.fee_waivers.contains(update_authority) {
+            true => 0,
+            false => self.generate_fee_lamports,
+        }
+    }
+
+    pub fn generate_fee_tokens_for(&self, update_authority: &Pubkey) -> u64 {
+        match self.fee_waivers.contains(update_authority) {
+            true => 0,
+            false => self.generate_fee_tokens,
+        }
+    }
+}
+
+// how the payer settles the generate fee; chosen per generate
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Debug)]
+pub enum FeePayment {
+    Lamports,
+    // in the fee mint of the config
+    Token,
+}
+
+#[repr(C)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default, PartialEq, Debug)]
+pub struct InitializeConfigInfo {
+    pub treasury: Pubkey,
+    pub generate_fee_lamports: u64,
+    pub fee_mint: Option<Pubkey>,
+    pub generate_fee_tokens: u64,
+    pub fee_waivers: Vec<Pubkey>,
+}
+
+// only the fields that are set will be changed
+#[repr(C)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default, PartialEq, Debug)]
+pub struct UpdateConfigInfo {
+    pub admin: Option<Pubkey>,
+    pub paused: Option<bool>,
+    pub treasury: Option<Pubkey>,
+    pub generate_fee_lamports: Option<u64>,
+    // Some(None) removes the spl token fee
+    pub fee_mint: Option<Option<Pubkey>>,
+    pub generate_fee_tokens: Option<u64>,
+    pub fee_waivers: Option<Vec<Pubkey>>,
+}
+
+// every MetadataInfo field except name and uri, shared by all nfts of a collection
+#[repr(C)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default, PartialEq, Debug)]
+pub struct MetadataTemplate {
+    pub symbol: String,
+    pub creators: Option<Vec<Creator>>,
+    pub seller_fee_basis_points: u16,
+    pub update_authority_is_signer: bool,
+    pub is_mutable: bool,
+    pub collection: Option<Collection>,
+    pub uses: Option<Uses>,
+    pub supply: MasterEditionSupply,
+    pub new_update_authority: Option<Pubkey>,
+}
+
+impl MetadataTemplate {
+    pub fn to_metadata_info(self, name: String, uri: String) -> MetadataInfo {
+        MetadataInfo {
+            name,
+            symbol: self.symbol,
+            uri,
+            creators: self.creators,
+            seller_fee_basis_points: self.seller_fee_basis_points,
+            update_authority_is_signer: self.update_authority_is_signer,
+            is_mutable: self.is_mutable,
+            collection: self.collection,
+            uses: self.uses,
+            supply: self.supply,
+            new_update_authority: self.new_update_authority,
+            content_hash: None,
+        }
+    }
+
+    // symbol, creators, seller_fee_basis_points, update_authority_is_signer, is_mutable,
+    // collection, uses, supply, new_update_authority
+    pub const LEN: usize = 4
+        + MAX_SYMBOL_LENGTH
+        + 1
+        + 4
+        + MAX_CREATOR_LIMIT * MAX_CREATOR_LEN
+        + 2
+        + 1
+        + 1
+        + 1
+        + 33
+        + 1
+        + 17
+        + 1
+        + 8
+        + 1
+        + 32;
+}
+
+// same fields as MetadataTemplate, but only the fields that are set replace the template values
+#[repr(C)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default, PartialEq, Debug)]
+pub struct TemplateOverrides {
+    pub symbol: Option<String>,
+    pub creators: Option<Vec<Creator>>,
+    pub seller_fee_basis_points: Option<u16>,
+    pub is_mutable: Option<bool>,
+    pub collection: Option<Collection>,
+    pub uses: Option<Uses>,
+    pub supply: Option<MasterEditionSupply>,
+    pub new_update_authority: Option<Pubkey>,
+}
+
+// metadata defaults of a collection, owned by the collection authority
+#[account]
+#[derive(Default)]
+pub struct CollectionTemplate {
+    pub collection: Pubkey,
+    pub authority: Pubkey,
+    pub template: MetadataTemplate,
+    pub bump: u8,
+}
+
+impl CollectionTemplate {
+    pub const SEED: &'static [u8] = b"template";
+    pub const LEN: usize = 8 + 32 + 32 + MetadataTemplate::LEN + 1;
+
+    pub fn to_metadata_info(
+        &self,
+        name: String,
+        uri: String,
+        overrides: TemplateOverrides,
+    ) -> MetadataInfo {
+        let template = self.template.clone();
+
+        MetadataTemplate {
+            symbol: overrides.symbol.unwrap_or(template.symbol),
+            creators: overrides.creators.or(template.creators),
+            seller_fee_basis_points: overrides
+                .seller_fee_basis_points
+                .unwrap_or(template.seller_fee_basis_points),
+            update_authority_is_signer: template.update_authority_is_signer,
+            is_mutable: overrides.is_mutable.unwrap_or(template.is_mutable),
+            collection: overrides.collection.or(template.collection),
+            uses: overrides.uses.or(template.uses),
+            supply: overrides.supply.unwrap_or(template.supply),
+            new_update_authority: overrides
+                .new_update_authority
+                .or(template.new_update_authority),
+        }
+        .to_metadata_info(name, uri)
+    }
+}
+
+// "prefix #N", as generated by generate_numbered
+pub fn numbered_name(prefix: &str, number: u64) -> String {
+    format!("{} #{}", prefix, number)
+}
+
+// "base_uri/N.json", as generated by generate_numbered
+pub fn numbered_uri(base_uri: &str, number: u64) -> String {
+    format!("{}/{}.json", base_uri.trim_end_matches('/'), number)
+}
+
+// numbers the nfts of a collection; every numbered generate takes the next number, and is named
+// "name_prefix #N" with uri "base_uri/N.json"
+#[account]
+#[derive(Default)]
+pub struct CollectionCounter {
+    pub collection: Pubkey,
+    pub authority: Pubkey,
+    pub count: u64,
+    pub max_supply: u64,
+    pub name_prefix: String,
+    pub base_uri: String,
+    pub bump: u8,
+}
+
+impl CollectionCounter {
+    pub const SEED: &'static [u8] = b"counter";
+    pub const LEN: usize = 8 + 32 + 32 + 8 + 8 + 4 + MAX_NAME_LENGTH + 4 + MAX_URI_LENGTH + 1;
+
+    pub fn next_number(&mut self) -> Result<u64, ProgramError> {
+        if self.count >= self.max_supply {
+            return Err(ErrorCode::CollectionMaxSupplyReached.into());
+        }
+        self.count += 1;
+
+        Ok(self.count)
+    }
+}
+
+// commitment to the final uris of a mint or a collection, see commit_reveal. doubles as update
+// authority of the committed metadata until it's revealed.
+#[account]
+#[derive(Default)]
+pub struct RevealCommitment {
+    // mint or collection the commitment is for
+    pub key: Pubkey,
+    pub authority: Pubkey,
+    pub root: [u8; 32],
+    pub reveal_at: i64,
+    pub bump: u8,
+}
+
+impl RevealCommitment {
+    pub const SEED: &'static [u8] = b"reveal";
+    pub const LEN: usize = 8 + 32 + 32 + 32 + 8 + 1;
+
+    // the content hash is committed along with the uri, so that whoever reveals can't set another
+    pub fn leaf(mint: &Pubkey, uri: &str, content_hash: Option<[u8; 32]>) -> [u8; 32] {
+        match content_hash {
+            Some(content_hash) => {
+                merkle::hash_leaf(&[mint.as_ref(), uri.as_bytes(), &content_hash])
+            }
+            None => merkle::hash_leaf(&[mint.as_ref(), uri.as_bytes()]),
+        }
+    }
+
+    pub fn signer_seeds(&self) -> [&[u8]; 3] {
+        [
+            Self::SEED,
+            self.key.as_ref(),
+            std::slice::from_ref(&self.bump),
+        ]
+    }
+}
+
+// merkle root of the wallets allowed to mint into a collection, see initialize_allowlist. doubles as
+// mint and update authority of allowlisted nfts while they're generated.
+#[account]
+#[derive(Default)]
+pub struct Allowlist {
+    pub collection: Pubkey,
+    pub authority: Pubkey,
+    pub root: [u8; 32],
+    pub bump: u8,
+}
+
+impl Allowlist {
+    pub const SEED: &'static [u8] = b"allowlist";
+    pub const LEN: usize = 8 + 32 + 32 + 32 + 1;
+
+    pub fn signer_seeds(&self) -> [&[u8]; 3] {
+        [
+            Self::SEED,
+            self.collection.as_ref(),
+            std::slice::from_ref(&self.bump),
+        ]
+    }
+
+    pub fn leaf(wallet: &Pubkey, max_mints: u64) -> [u8; 32] {
+        merkle::hash_leaf(&[wallet.as_ref(), &max_mints.to_le_bytes()])
+    }
+}
+
+// number of mints an allowlisted wallet has made
+#[account]
+#[derive(Default)]
+pub struct ClaimStatus {
+    pub allowlist: Pubkey,
+    pub claimant: Pubkey,
+    pub minted: u64,
+}
+
+impl ClaimStatus {
+    pub const SEED: &'static [u8] = b"claim_status";
+    pub const LEN: usize = 8 + 32 + 32 + 8;
+}
+
+#[repr(C)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default, PartialEq, Debug)]
+pub struct DropInfo {
+    pub treasury: Pubkey,
+    pub price_lamports: u64,
+    pub go_live_at: i64,
+    pub end_at: i64,
+    pub max_items: u64,
+    // items are named "name_prefix #N" with uri "base_uri/N.json", counting from 1
+    pub name_prefix: String,
+    pub base_uri: String,
+}
+
+// primary sale of a collection, see mint_from_drop. every item is numbered; all other metadata
+// comes from the collection template. the drop signs as mint and update authority of its items.
+#[account]
+#[derive(Default)]
+pub struct CollectionDrop {
+    pub collection: Pubkey,
+    pub authority: Pubkey,
+    pub treasury: Pubkey,
+    pub price_lamports: u64,
+    pub go_live_at: i64,
+    pub end_at: i64,
+    pub max_items: u64,
+    pub items_minted: u64,
+    pub name_prefix: String,
+    pub base_uri: String,
+    pub bump: u8,
+}
+
+impl CollectionDrop {
+    pub const SEED: &'static [u8] = b"drop";
+    pub const LEN: usize =
+        8 + 32 + 32 + 32 + 8 + 8 + 8 + 8 + 8 + 4 + MAX_NAME_LENGTH + 4 + MAX_URI_LENGTH + 1;
+
+    pub fn signer_seeds(&self) -> [&[u8]; 3] {
+        [
+            Self::SEED,
+            self.collection.as_ref(),
+            std::slice::from_ref(&self.bump),
+        ]
+    }
+}
+
+// sha-256 of the off-chain json of a mint, as last set by generate or an update. it's cleared
+// when the uri changes without a new hash, so it never describes the json of an earlier uri.
+#[account]
+#[derive(Default)]
+pub struct ContentHash {
+    pub mint: Pubkey,
+    pub hash: Option<[u8; 32]>,
+    // slot the hash was last set or cleared in; 0 until then
+    pub slot: u64,
+}
+
+impl ContentHash {
+    pub const SEED: &'static [u8] = b"content_hash";
+    pub const LEN: usize = 8 + 32 + 1 + 32 + 8;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config(fee_waivers: Vec<Pubkey>) -> Config {
+        Config {
+            generate_fee_lamports: 1_000,
+            fee_waivers,
+            ..Config::default()
+        }
+    }
+
+    #[test]
+    fn generate_fee_lamports_charged_without_waiver() {
+        let config = config(vec![Pubkey::new_unique()]);
+
+        assert_eq!(
+            config.generate_fee_lamports_for(&Pubkey::new_unique()),
+            1_000
+        );
+    }
+
+    #[test]
+    fn generate_fee_lamports_waived() {
+        let waived = Pubkey::new_unique();
+        let config = config(vec![Pubkey::new_unique(), waived]);
+
+        assert_eq!(config.generate_fee_lamports_for(&waived), 0);
+    }
+
+    #[test]
+    fn generate_fee_tokens_charged_without_waiver() {
+        let config = Config {
+            generate_fee_tokens: 5,
+            ..config(vec![Pubkey::new_unique()])
+        };
+
+        assert_eq!(config.generate_fee_tokens_for(&Pubkey::new_unique()), 5);
+    }
+
+    #[test]
+    fn generate_fee_tokens_waived() {
+        let waived = Pubkey::new_unique();
+        let config = Config {
+            generate_fee_tokens: 5,
+            ..config(vec![waived])
+        };
+
+        assert_eq!(config.generate_fee_tokens_for(&waived), 0);
+    }
+
+    #[test]
+    fn numbered_name_appends_number() {
+        assert_eq!(numbered_name("Ape", 421), "Ape #421");
+    }
+
+    #[test]
+    fn numbered_uri_trims_trailing_slash() {
+        assert_eq!(
+            numbered_uri("https://someserver.com/apes", 7),
+            "https://someserver.com/apes/7.json"
+        );
+        assert_eq!(
+            numbered_uri("https://someserver.com/apes/", 7),
+            "https://someserver.com/apes/7.json"
+        );
+    }
+
+    #[test]
+    fn reveal_leaf_commits_to_content_hash() {
+        let mint = Pubkey::new_unique();
+        let uri = "https://someserver.com/apes/7.json";
+
+        assert_ne!(
+            RevealCommitment::leaf(&mint, uri, None),
+            RevealCommitment::leaf(&mint, uri, Some([7; 32]))
+        );
+        assert_ne!(
+            RevealCommitment::leaf(&mint, uri, Some([7; 32])),
+            RevealCommitment::leaf(&mint, uri, Some([8; 32]))
+        );
+    }
+
+    #[test]
+    fn counter_stops_at_max_supply() {
+        let mut counter = CollectionCounter {
+            max_supply: 2,
+            ..CollectionCounter::default()
+        };
+
+        assert_eq!(counter.next_number(), Ok(1));
+        assert_eq!(counter.next_number(), Ok(2));
+        assert_eq!(
+            counter.next_number(),
+            Err(ErrorCode::CollectionMaxSupplyReached.into())
+        );
+        assert_eq!(counter.count, 2);
+    }
+}
@@ -0,0 +1,246 @@
+use {
+    crate::{numbered_name, numbered_uri, DropInfo, ErrorCode, MetadataTemplate},
+    anchor_lang::prelude::*,
+    mpl_token_metadata::state::{
+        DataV2, MAX_CREATOR_LIMIT, MAX_NAME_LENGTH, MAX_SYMBOL_LENGTH, MAX_URI_LENGTH,
+    },
+};
+
+// max royalty; 100% in basis points
+pub const MAX_SELLER_FEE_BASIS_POINTS: u16 = 10000;
+
+// checks metadata against the same limits enforced by the metaplex metadata program, so that bad
+// input fails with a precise error before paying for the cpi.
+pub fn validate_data(data: &DataV2) -> ProgramResult {
+    if data.name.len() > MAX_NAME_LENGTH {
+        return Err(ErrorCode::NameTooLong.into());
+    }
+
+    if data.symbol.len() > MAX_SYMBOL_LENGTH {
+        return Err(ErrorCode::SymbolTooLong.into());
+    }
+
+    if data.uri.len() > MAX_URI_LENGTH {
+        return Err(ErrorCode::UriTooLong.into());
+    }
+
+    if data.seller_fee_basis_points > MAX_SELLER_FEE_BASIS_POINTS {
+        return Err(ErrorCode::InvalidSellerFeeBasisPoints.into());
+    }
+
+    if let Some(creators) = &data.creators {
+        if creators.len() > MAX_CREATOR_LIMIT {
+            return Err(ErrorCode::TooManyCreators.into());
+        }
+
+        for (i, creator) in creators.iter().enumerate() {
+            if creators[..i]
+                .iter()
+                .any(|other| other.address == creator.address)
+            {
+                return Err(ErrorCode::DuplicateCreator.into());
+            }
+        }
+
+        let total_shares: u16 = creators.iter().map(|creator| creator.share as u16).sum();
+        if total_shares != 100 {
+            return Err(ErrorCode::InvalidCreatorShares.into());
+        }
+    }
+
+    if let Some(uses) = &data.uses {
+        if uses.remaining > uses.total {
+            return Err(ErrorCode::InvalidUses.into());
+        }
+    }
+
+    Ok(())
+}
+
+// templates are only checked for what has to fit in the template account; the metadata generated
+// from them goes through validate_data like any other
+pub fn validate_template(template: &MetadataTemplate) -> ProgramResult {
+    if template.symbol.len() > MAX_SYMBOL_LENGTH {
+        return Err(ErrorCode::SymbolTooLong.into());
+    }
+
+    if let Some(creators) = &template.creators {
+        if creators.len() > MAX_CREATOR_LIMIT {
+            return Err(ErrorCode::TooManyCreators.into());
+        }
+    }
+
+    Ok(())
+}
+
+// the numbered name and uri of the last item have to fit the metaplex limits
+pub fn validate_numbering(name_prefix: &str, base_uri: &str, last_number: u64) -> ProgramResult {
+    if numbered_name(name_prefix, last_number).len() > MAX_NAME_LENGTH {
+        return Err(ErrorCode::NameTooLong.into());
+    }
+
+    if numbered_uri(base_uri, last_number).len() > MAX_URI_LENGTH {
+        return Err(ErrorCode::UriTooLong.into());
+    }
+
+    Ok(())
+}
+
+pub fn validate_drop(info: &DropInfo) -> ProgramResult {
+    if info.go_live_at >= info.end_at {
+        return Err(ErrorCode::InvalidDropTimes.into());
+    }
+
+    validate_numbering(&info.name_prefix, &info.base_uri, info.max_items)
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        mpl_token_metadata::state::{Creator, UseMethod, Uses},
+    };
+
+    fn data() -> DataV2 {
+        DataV2 {
+            name: "name".to_string(),
+            symbol: "SYMB".to_string(),
+            uri: "https://someserver.com".to_string(),
+            seller_fee_basis_points: 420,
+            creators: Some(vec![
+                Creator {
+                    address: Pubkey::new_unique(),
+                    verified: true,
+                    share: 60,
+                },
+                Creator {
+                    address: Pubkey::new_unique(),
+                    verified: false,
+                    share: 40,
+                },
+            ]),
+            collection: None,
+            uses: None,
+        }
+    }
+
+    fn creator(share: u8) -> Creator {
+        Creator {
+            address: Pubkey::new_unique(),
+            verified: false,
+            share,
+        }
+    }
+
+    #[test]
+    fn accepts_data_within_limits() {
+        assert_eq!(validate_data(&data()), Ok(()));
+
+        let data = DataV2 {
+            name: "n".repeat(MAX_NAME_LENGTH),
+            symbol: "s".repeat(MAX_SYMBOL_LENGTH),
+            uri: "u".repeat(MAX_URI_LENGTH),
+            seller_fee_basis_points: MAX_SELLER_FEE_BASIS_POINTS,
+            creators: None,
+            ..data()
+        };
+        assert_eq!(validate_data(&data), Ok(()));
+    }
+
+    #[test]
+    fn rejects_strings_over_the_metaplex_limits() {
+        let mut name = data();
+        name.name = "n".repeat(MAX_NAME_LENGTH + 1);
+        assert_eq!(validate_data(&name), Err(ErrorCode::NameTooLong.into()));
+
+        let mut symbol = data();
+        symbol.symbol = "s".repeat(MAX_SYMBOL_LENGTH + 1);
+        assert_eq!(validate_data(&symbol), Err(ErrorCode::SymbolTooLong.into()));
+
+        let mut uri = data();
+        uri.uri = "u".repeat(MAX_URI_LENGTH + 1);
+        assert_eq!(validate_data(&uri), Err(ErrorCode::UriTooLong.into()));
+    }
+
+    #[test]
+    fn rejects_royalties_over_100_percent() {
+        let mut data = data();
+        data.seller_fee_basis_points = MAX_SELLER_FEE_BASIS_POINTS + 1;
+
+        assert_eq!(
+            validate_data(&data),
+            Err(ErrorCode::InvalidSellerFeeBasisPoints.into())
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_creators() {
+        let mut too_many = data();
+        too_many.creators = Some(vec![creator(20); MAX_CREATOR_LIMIT + 1]);
+        assert_eq!(
+            validate_data(&too_many),
+            Err(ErrorCode::TooManyCreators.into())
+        );
+
+        let mut duplicate = data();
+        let twice = creator(50);
+        duplicate.creators = Some(vec![twice.clone(), twice]);
+        assert_eq!(
+            validate_data(&duplicate),
+            Err(ErrorCode::DuplicateCreator.into())
+        );
+
+        let mut shares = data();
+        shares.creators = Some(vec![creator(60), creator(30)]);
+        assert_eq!(
+            validate_data(&shares),
+            Err(ErrorCode::InvalidCreatorShares.into())
+        );
+    }
+
+    #[test]
+    fn rejects_more_remaining_uses_than_total() {
+        let mut data = data();
+        data.uses = Some(Uses {
+            use_method: UseMethod::Multiple,
+            remaining: 6,
+            total: 5,
+        });
+
+        assert_eq!(validate_data(&data), Err(ErrorCode::InvalidUses.into()));
+    }
+
+    #[test]
+    fn rejects_drops_whose_last_item_does_not_fit() {
+        let info = DropInfo {
+            go_live_at: 0,
+            end_at: 1,
+            max_items: 1000,
+            name_prefix: "n".repeat(MAX_NAME_LENGTH - 6),
+            base_uri: "u".repeat(MAX_URI_LENGTH - 10),
+            ..DropInfo::default()
+        };
+        assert_eq!(validate_drop(&info), Ok(()));
+
+        let times = DropInfo {
+            end_at: 0,
+            ..info.clone()
+        };
+        assert_eq!(
+            validate_drop(&times),
+            Err(ErrorCode::InvalidDropTimes.into())
+        );
+
+        let name = DropInfo {
+            max_items: 10000,
+            ..info.clone()
+        };
+        assert_eq!(validate_drop(&name), Err(ErrorCode::NameTooLong.into()));
+
+        let uri = DropInfo {
+            base_uri: "u".repeat(MAX_URI_LENGTH - 9),
+            ..info
+        };
+        assert_eq!(validate_drop(&uri), Err(ErrorCode::UriTooLong.into()));
+    }
+}
@@ -9,6 +9,8 @@ export * from "./borsh";
 export const METADATA_PREFIX = "metadata";
 export const EDITION = "edition";
 export const RESERVATION = "reservation";
+export const USER = "user";
+export const BURN = "burn";
 export const MAX_NAME_LENGTH = 32;
 export const MAX_SYMBOL_LENGTH = 10;
 export const MAX_URI_LENGTH = 200;
@@ -175,6 +177,28 @@ export class Creator {
   }
 }
 
+export class Collection {
+  verified: boolean;
+  key: StringPublicKey;
+
+  constructor(args: { verified: boolean; key: StringPublicKey }) {
+    this.verified = args.verified;
+    this.key = args.key;
+  }
+}
+
+export class Uses {
+  useMethod: number;
+  remaining: BN;
+  total: BN;
+
+  constructor(args: { useMethod: number; remaining: BN; total: BN }) {
+    this.useMethod = args.useMethod;
+    this.remaining = args.remaining;
+    this.total = args.total;
+  }
+}
+
 export class Data {
   name: string;
   symbol: string;
@@ -204,6 +228,9 @@ export class Metadata {
   primarySaleHappened: boolean;
   isMutable: boolean;
   editionNonce: number | null;
+  tokenStandard: number | null;
+  collection: Collection | null;
+  uses: Uses | null;
 
   // set lazy
   masterEdition?: StringPublicKey;
@@ -216,6 +243,9 @@ export class Metadata {
     primarySaleHappened: boolean;
     isMutable: boolean;
     editionNonce: number | null;
+    tokenStandard: number | null;
+    collection: Collection | null;
+    uses: Uses | null;
   }) {
     this.key = MetadataKey.MetadataV1;
     this.updateAuthority = args.updateAuthority;
@@ -224,6 +254,9 @@ export class Metadata {
     this.primarySaleHappened = args.primarySaleHappened;
     this.isMutable = args.isMutable;
     this.editionNonce = args.editionNonce;
+    this.tokenStandard = args.tokenStandard;
+    this.collection = args.collection;
+    this.uses = args.uses;
   }
 
   public async init() {
@@ -293,6 +326,27 @@ export const METADATA_SCHEMA = new Map<any, any>([
       ],
     },
   ],
+  [
+    Collection,
+    {
+      kind: "struct",
+      fields: [
+        ["verified", "u8"], // bool
+        ["key", "pubkeyAsString"],
+      ],
+    },
+  ],
+  [
+    Uses,
+    {
+      kind: "struct",
+      fields: [
+        ["useMethod", "u8"],
+        ["remaining", "u64"],
+        ["total", "u64"],
+      ],
+    },
+  ],
   [
     Metadata,
     {
@@ -304,6 +358,10 @@ export const METADATA_SCHEMA = new Map<any, any>([
         ["data", Data],
         ["primarySaleHappened", "u8"], // bool
         ["isMutable", "u8"], // bool
+        ["editionNonce", { kind: "option", type: "u8" }],
+        ["tokenStandard", { kind: "option", type: "u8" }],
+        ["collection", { kind: "option", type: Collection }],
+        ["uses", { kind: "option", type: Uses }],
       ],
     },
   ],
@@ -375,6 +433,62 @@ export const getMasterEdition = async (
   )[0];
 };
 
+// every edition marker tracks EDITION_MARKER_BIT_SIZE printed editions of a master edition
+export const getEditionMarker = async (
+  mint: PublicKey,
+  edition: number
+): Promise<PublicKey> => {
+  const PROGRAM_IDS = programIds();
+
+  return (
+    await findProgramAddress(
+      [
+        Buffer.from(METADATA_PREFIX),
+        toPublicKey(PROGRAM_IDS.metadata).toBuffer(),
+        mint.toBuffer(),
+        Buffer.from(EDITION),
+        Buffer.from(Math.floor(edition / EDITION_MARKER_BIT_SIZE).toString()),
+      ],
+      toPublicKey(PROGRAM_IDS.metadata)
+    )
+  )[0];
+};
+
+export const getUseAuthorityRecord = async (
+  mint: PublicKey,
+  user: PublicKey
+): Promise<PublicKey> => {
+  const PROGRAM_IDS = programIds();
+
+  return (
+    await findProgramAddress(
+      [
+        Buffer.from(METADATA_PREFIX),
+        toPublicKey(PROGRAM_IDS.metadata).toBuffer(),
+        mint.toBuffer(),
+        Buffer.from(USER),
+        user.toBuffer(),
+      ],
+      toPublicKey(PROGRAM_IDS.metadata)
+    )
+  )[0];
+};
+
+export const getBurner = async (): Promise<PublicKey> => {
+  const PROGRAM_IDS = programIds();
+
+  return (
+    await findProgramAddress(
+      [
+        Buffer.from(METADATA_PREFIX),
+        toPublicKey(PROGRAM_IDS.metadata).toBuffer(),
+        Buffer.from(BURN),
+      ],
+      toPublicKey(PROGRAM_IDS.metadata)
+    )
+  )[0];
+};
+
 export const decodeMasterEdition = (
   buffer: Buffer
 ): MasterEditionV1 | MasterEditionV2 => {
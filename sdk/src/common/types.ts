@@ -1,3 +1,4 @@
+import { BN } from "@project-serum/anchor";
 import { u64 } from "@solana/spl-token";
 import { Keypair, PublicKey } from "@solana/web3.js";
 
@@ -13,6 +14,25 @@ export interface MetadataCreator {
     share: number;
 }
 
+// avoid collisions with metaplex Collection struct
+export interface MetadataCollection {
+  verified: boolean;
+  key: PublicKey;
+}
+
+// avoid collisions with metaplex Uses struct
+export interface MetadataUses {
+  useMethod: any; // UseMethod, e.g. { multiple: {} }
+  remaining: u64;
+  total: u64;
+}
+
+// no master edition, a master edition with a max supply of printed editions, or one without
+export type MasterEditionSupply =
+  | { none: {} }
+  | { limited: { maxSupply: u64 } }
+  | { unlimited: {} };
+
 export interface MetadataInfo {
   name: string;
   symbol: string;
@@ -21,10 +41,11 @@ export interface MetadataInfo {
   updateAuthorityIsSigner: boolean;
   isMutable: boolean;
   creators?: MetadataCreator[]; // Creator
-  collection?: PublicKey; // Collection
-  uses?: any; // Uses
-  supply?: u64; // u64
+  collection?: MetadataCollection; // Collection
+  uses?: MetadataUses; // Uses
+  supply: MasterEditionSupply; // MasterEditionSupply
   newUpdateAuthority?: PublicKey;
+  contentHash?: number[]; // [u8; 32], sha-256 of the json at uri; see hashContent
 }
 
 export interface UpdateMetadataInfo {
@@ -33,6 +54,83 @@ export interface UpdateMetadataInfo {
   uri: string;
   sellerFeeBasisPoints: number; // u16
   creators?: MetadataCreator[]; // Creator
-  collection?: PublicKey; // Collection
-  uses?: any; // Uses
+  collection?: MetadataCollection; // Collection
+  uses?: MetadataUses; // Uses
+  contentHash?: number[]; // [u8; 32]
+}
+
+// only the fields that are set are changed, everything else is kept as is
+export interface PatchMetadataInfo {
+  name?: string;
+  symbol?: string;
+  uri?: string;
+  sellerFeeBasisPoints?: number; // u16
+  creators?: MetadataCreator[]; // Creator
+  collection?: MetadataCollection; // Collection
+  uses?: MetadataUses; // Uses
+  contentHash?: number[]; // [u8; 32]
+}
+
+// change applied by a timelock once its delay passed, or by a multisig once enough owners approved it
+export type QueuedMetadataChange =
+  | { data: { "0": UpdateMetadataInfo } }
+  | { uri: { "0": string; "1": number[] | null } } // uri and content hash of its json
+  | { updateAuthority: { "0": PublicKey } };
+
+// how the payer settles the generate fee; the spl token fee is paid in the fee mint of the config
+export type FeePayment = { lamports: {} } | { token: {} };
+
+export interface InitializeConfigInfo {
+  treasury: PublicKey;
+  generateFeeLamports: BN; // u64
+  feeMint?: PublicKey;
+  generateFeeTokens: BN; // u64
+  feeWaivers: PublicKey[]; // update authorities that don't pay the generate fee
+}
+
+// only the fields that are set are changed, everything else is kept as is
+export interface UpdateConfigInfo {
+  admin?: PublicKey;
+  paused?: boolean;
+  treasury?: PublicKey;
+  generateFeeLamports?: BN; // u64
+  feeMint?: PublicKey;
+  generateFeeTokens?: BN; // u64
+  feeWaivers?: PublicKey[];
+}
+
+// every MetadataInfo field except name and uri, shared by all nfts of a collection
+export interface MetadataTemplate {
+  symbol: string;
+  creators?: MetadataCreator[]; // Creator
+  sellerFeeBasisPoints: number; // u16
+  updateAuthorityIsSigner: boolean;
+  isMutable: boolean;
+  collection?: MetadataCollection; // Collection
+  uses?: MetadataUses; // Uses
+  supply: MasterEditionSupply; // MasterEditionSupply
+  newUpdateAuthority?: PublicKey;
+}
+
+// only the fields that are set replace the template values
+export interface TemplateOverrides {
+  symbol?: string;
+  creators?: MetadataCreator[]; // Creator
+  sellerFeeBasisPoints?: number; // u16
+  isMutable?: boolean;
+  collection?: MetadataCollection; // Collection
+  uses?: MetadataUses; // Uses
+  supply?: MasterEditionSupply; // MasterEditionSupply
+  newUpdateAuthority?: PublicKey;
+}
+
+export interface DropInfo {
+  treasury: PublicKey;
+  priceLamports: BN; // u64
+  goLiveAt: BN; // i64, unix timestamp
+  endAt: BN; // i64, unix timestamp
+  maxItems: BN; // u64
+  // items are named "namePrefix #N" with uri "baseUri/N.json", counting from 1
+  namePrefix: string;
+  baseUri: string;
 }
@@ -2,6 +2,8 @@ import {
   Keypair,
   PublicKey,
 } from "@solana/web3.js";
+import { BN } from "@project-serum/anchor";
+import { createHash } from "crypto";
 
 import { SignerInfo } from "./types";
 
@@ -22,3 +24,47 @@ export const getSignersFromPayer = (payer: PublicKey | Keypair): SignerInfo => {
     signers,
   } as SignerInfo;
 };
+
+// leaves and nodes are hashed with distinct prefixes, like the program does
+const LEAF_PREFIX = Buffer.from([0]);
+const NODE_PREFIX = Buffer.from([1]);
+
+// leaf of a reveal commitment; a per mint commitment uses the leaf of that mint as root
+export const revealLeaf = (
+  mint: PublicKey,
+  uri: string,
+  contentHash?: number[]
+): Buffer => {
+  const hash = createHash("sha256")
+    .update(LEAF_PREFIX)
+    .update(mint.toBuffer())
+    .update(uri);
+  if (contentHash) {
+    hash.update(Buffer.from(contentHash));
+  }
+  return hash.digest();
+};
+
+// sha-256 of the off-chain json, as stored in the content hash account of a mint
+export const hashContent = (json: string | Buffer): number[] => {
+  return Array.from(createHash("sha256").update(json).digest());
+};
+
+// leaf of an allowlist; maxMints = 0 means the wallet can mint without limit
+export const allowlistLeaf = (wallet: PublicKey, maxMints: number): Buffer => {
+  return createHash("sha256")
+    .update(LEAF_PREFIX)
+    .update(wallet.toBuffer())
+    .update(new BN(maxMints).toArrayLike(Buffer, "le", 8))
+    .digest();
+};
+
+// parent of two merkle nodes; pairs are hashed in sorted order like the program does
+export const hashMerkleNodes = (a: Buffer, b: Buffer): Buffer => {
+  const [first, second] = Buffer.compare(a, b) <= 0 ? [a, b] : [b, a];
+  return createHash("sha256")
+    .update(NODE_PREFIX)
+    .update(first)
+    .update(second)
+    .digest();
+};
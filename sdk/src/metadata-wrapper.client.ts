@@ -1,5 +1,5 @@
 import * as anchor from "@project-serum/anchor";
-import { Program, Provider, Idl, Wallet } from "@project-serum/anchor";
+import { Program, Provider, Idl, Wallet, BN } from "@project-serum/anchor";
 import {
   TOKEN_PROGRAM_ID,
   Token,
@@ -14,9 +14,21 @@ import {
   SYSVAR_RENT_PUBKEY,
 } from "@solana/web3.js";
 
+const BPF_LOADER_UPGRADEABLE_PROGRAM_ID = new PublicKey(
+  "BPFLoaderUpgradeab1e11111111111111111111111"
+);
+
 import {
   MetadataInfo,
   UpdateMetadataInfo,
+  PatchMetadataInfo,
+  QueuedMetadataChange,
+  InitializeConfigInfo,
+  UpdateConfigInfo,
+  FeePayment,
+  MetadataTemplate,
+  TemplateOverrides,
+  DropInfo,
   TOKEN_METADATA_PROGRAM_ID,
   AccountUtils,
   getSignersFromPayer,
@@ -27,6 +39,9 @@ import {
   MasterEditionV1,
   getMetadata as _getMetadata,
   getMasterEdition as _getMasterEdition,
+  getEditionMarker as _getEditionMarker,
+  getUseAuthorityRecord as _getUseAuthorityRecord,
+  getBurner as _getBurner,
 } from "./common";
 import { MetadataWrapper } from "./types/metadata_wrapper";
 
@@ -88,9 +103,188 @@ export class MetadataWrapperClient extends AccountUtils {
     return _getMetadata(mint);
   };
 
+  getEditionMarker = async (
+    masterMint: PublicKey,
+    edition: number
+  ): Promise<PublicKey> => {
+    return _getEditionMarker(masterMint, edition);
+  };
+
+  getUseAuthorityRecord = async (
+    mint: PublicKey,
+    user: PublicKey
+  ): Promise<PublicKey> => {
+    return _getUseAuthorityRecord(mint, user);
+  };
+
+  getBurner = async (): Promise<PublicKey> => {
+    return _getBurner();
+  };
+
+  getPendingUpdateAuthority = async (
+    mint: PublicKey
+  ): Promise<[PublicKey, number]> => {
+    return this.findProgramAddress(this.program.programId, [
+      "pending_update_authority",
+      await this.getMetadata(mint),
+    ]);
+  };
+
+  getAuthorityVault = async (
+    collection: PublicKey
+  ): Promise<[PublicKey, number]> => {
+    return this.findProgramAddress(this.program.programId, [
+      "authority",
+      collection,
+    ]);
+  };
+
+  getMultisig = async (base: PublicKey): Promise<[PublicKey, number]> => {
+    return this.findProgramAddress(this.program.programId, ["multisig", base]);
+  };
+
+  getMultisigProposal = async (
+    multisig: PublicKey,
+    index: BN
+  ): Promise<PublicKey> => {
+    const [pda] = await this.findProgramAddress(this.program.programId, [
+      "multisig_proposal",
+      multisig,
+      index.toArrayLike(Buffer, "le", 8),
+    ]);
+    return pda;
+  };
+
+  getTimelock = async (collection: PublicKey): Promise<[PublicKey, number]> => {
+    return this.findProgramAddress(this.program.programId, [
+      "timelock",
+      collection,
+    ]);
+  };
+
+  getQueuedMetadataUpdate = async (mint: PublicKey): Promise<PublicKey> => {
+    const [pda] = await this.findProgramAddress(this.program.programId, [
+      "queued_metadata_update",
+      await this.getMetadata(mint),
+    ]);
+    return pda;
+  };
+
+  getMetadataHistory = async (mint: PublicKey): Promise<PublicKey> => {
+    const [pda] = await this.findProgramAddress(this.program.programId, [
+      "history",
+      mint,
+    ]);
+    return pda;
+  };
+
+  getContentHash = async (mint: PublicKey): Promise<PublicKey> => {
+    const [pda] = await this.findProgramAddress(this.program.programId, [
+      "content_hash",
+      mint,
+    ]);
+    return pda;
+  };
+
+  // update instructions only record history and the content hash of the mint when these accounts
+  // are passed, so always pass them; they're skipped if the mint never initialized them
+  updateRemainingAccounts = async (mint: PublicKey) => {
+    return [
+      await this.getMetadataHistory(mint),
+      await this.getContentHash(mint),
+    ].map((pubkey) => ({ pubkey, isWritable: true, isSigner: false }));
+  };
+
+  // generate instructions take the fee accounts first, then the content hash account of the mint
+  generateRemainingAccounts = async (
+    feePayment: FeePayment,
+    payer: PublicKey,
+    mint: PublicKey
+  ) => {
+    return [
+      ...(await this.feeRemainingAccounts(feePayment, payer)),
+      {
+        pubkey: await this.getContentHash(mint),
+        isWritable: true,
+        isSigner: false,
+      },
+    ];
+  };
+
+  getConfig = async (): Promise<[PublicKey, number]> => {
+    return this.findProgramAddress(this.program.programId, ["config"]);
+  };
+
+  getProgramData = async (): Promise<PublicKey> => {
+    const [pda] = await PublicKey.findProgramAddress(
+      [this.program.programId.toBuffer()],
+      BPF_LOADER_UPGRADEABLE_PROGRAM_ID
+    );
+    return pda;
+  };
+
+  // every instruction except the config ones checks that the program isn't paused
+  unpausedAccounts = async () => {
+    const [config] = await this.getConfig();
+    return { config };
+  };
+
   // ================================================
   // Fetch & deserialize objects
   // ================================================
+  // generate fees go to the treasury set in the config
+  getTreasury = async (): Promise<PublicKey> => {
+    const [config] = await this.getConfig();
+    return (await this.program.account.config.fetch(config)).treasury;
+  };
+
+  getCollectionTemplate = async (
+    collection: PublicKey
+  ): Promise<[PublicKey, number]> => {
+    return this.findProgramAddress(this.program.programId, [
+      "template",
+      collection,
+    ]);
+  };
+
+  getCollectionCounter = async (
+    collection: PublicKey
+  ): Promise<[PublicKey, number]> => {
+    return this.findProgramAddress(this.program.programId, [
+      "counter",
+      collection,
+    ]);
+  };
+
+  getAllowlist = async (collection: PublicKey): Promise<[PublicKey, number]> => {
+    return this.findProgramAddress(this.program.programId, [
+      "allowlist",
+      collection,
+    ]);
+  };
+
+  getClaimStatus = async (
+    allowlist: PublicKey,
+    wallet: PublicKey
+  ): Promise<PublicKey> => {
+    const [pda] = await this.findProgramAddress(this.program.programId, [
+      "claim_status",
+      allowlist,
+      wallet,
+    ]);
+    return pda;
+  };
+
+  getDrop = async (collection: PublicKey): Promise<[PublicKey, number]> => {
+    return this.findProgramAddress(this.program.programId, ["drop", collection]);
+  };
+
+  // key is the committed mint or collection
+  getRevealCommitment = async (
+    key: PublicKey
+  ): Promise<[PublicKey, number]> => {
+    return this.findProgramAddress(this.program.programId, ["reveal", key]);
+  };
 
   fetchMetadata = async (metadata: PublicKey): Promise<Metadata> => {
     const accountInfo = await this.connection.getAccountInfo(metadata);
@@ -181,21 +375,41 @@ export class MetadataWrapperClient extends AccountUtils {
         mint: mint.publicKey,
         mintAuthority: signerInfo.payer,
         updateAuthority: signerInfo.payer,
+        treasury: await this.getTreasury(),
         metadata,
         masterEdition,
         tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
         tokenProgram: TOKEN_PROGRAM_ID,
         systemProgram: SystemProgram.programId,
         rent: SYSVAR_RENT_PUBKEY,
+        unpaused: await this.unpausedAccounts(),
       },
       signers: signerInfo.signers,
     };
   };
 
+  // a token fee payment is settled from the payer's associated token account of the fee mint to
+  // the treasury's, which both have to exist
+  feeRemainingAccounts = async (feePayment: FeePayment, payer: PublicKey) => {
+    if (!("token" in feePayment)) {
+      return [];
+    }
+
+    const [config] = await this.getConfig();
+    const { feeMint, treasury } = await this.program.account.config.fetch(
+      config
+    );
+    return [
+      await this.findAssociatedTokenAddress(payer, feeMint),
+      await this.findAssociatedTokenAddress(treasury, feeMint),
+    ].map((pubkey) => ({ pubkey, isWritable: true, isSigner: false }));
+  };
+
   generateMetadata = async (
     mint: Keypair,
     metadataInfo: MetadataInfo,
-    payer: PublicKey | Keypair
+    payer: PublicKey | Keypair,
+    feePayment: FeePayment = { lamports: {} }
   ) => {
     const generateMetadataAccounts = await this.generateMetadataAccounts(
       mint,
@@ -204,25 +418,141 @@ export class MetadataWrapperClient extends AccountUtils {
 
     const _payer = generateMetadataAccounts.accounts.payer;
 
-    const tx = await this.program.rpc.generate(metadataInfo as any, {
-      accounts: generateMetadataAccounts.accounts,
+    const tx = await this.program.rpc.generate(
+      { contentHash: null, ...metadataInfo } as any,
+      feePayment as any,
+      {
+        accounts: generateMetadataAccounts.accounts,
+        remainingAccounts: await this.generateRemainingAccounts(
+          feePayment,
+          _payer,
+          mint.publicKey
+        ),
+        preInstructions: await this.generateMintInstructions(
+          mint.publicKey,
+          _payer,
+          _payer,
+          _payer,
+          _payer
+        ),
+        signers: [...generateMetadataAccounts.signers, mint],
+      }
+    );
+
+    return {
+      tx,
+      mint: mint.publicKey,
+      metadata: generateMetadataAccounts.accounts.metadata,
+      masterEdition:
+        "none" in metadataInfo.supply
+          ? undefined
+          : generateMetadataAccounts.accounts.masterEdition,
+    };
+  };
+
+  // creates the mint, the recipient's associated token account and the token in the same
+  // instruction as the metadata
+  generateMetadataWithMint = async (
+    mint: Keypair,
+    recipient: PublicKey,
+    metadataInfo: MetadataInfo,
+    payer: PublicKey | Keypair,
+    feePayment: FeePayment = { lamports: {} }
+  ) => {
+    const generateMetadataAccounts = await this.generateMetadataAccounts(
+      mint,
+      payer
+    );
+    const tokenAccount = await this.findAssociatedTokenAddress(
+      recipient,
+      mint.publicKey
+    );
+
+    const tx = await this.program.rpc.generateWithMint(
+      { contentHash: null, ...metadataInfo } as any,
+      feePayment as any,
+      {
+        accounts: {
+          ...generateMetadataAccounts.accounts,
+          recipient,
+          tokenAccount,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        },
+        remainingAccounts: await this.generateRemainingAccounts(
+          feePayment,
+          generateMetadataAccounts.accounts.payer,
+          mint.publicKey
+        ),
+        signers: [...generateMetadataAccounts.signers, mint],
+      }
+    );
+
+    return {
+      tx,
+      mint: mint.publicKey,
+      tokenAccount,
+      metadata: generateMetadataAccounts.accounts.metadata,
+      masterEdition:
+        "none" in metadataInfo.supply
+          ? undefined
+          : generateMetadataAccounts.accounts.masterEdition,
+    };
+  };
+
+  // prints the next edition of a master edition held by the owner to a new mint, which is created
+  // with a single token owned by the owner
+  printEdition = async (
+    masterMint: PublicKey,
+    newMint: Keypair,
+    newUpdateAuthority: PublicKey,
+    owner: PublicKey | Keypair
+  ) => {
+    const signerInfo = getSignersFromPayer(owner);
+    const _owner = signerInfo.payer;
+
+    const masterEdition = await this.getMasterEdition(masterMint);
+    const { supply } = await this.fetchMasterEdition(masterEdition);
+    const edition = supply.toNumber() + 1;
+
+    const newMetadata = await this.getMetadata(newMint.publicKey);
+    const newEdition = await this.getMasterEdition(newMint.publicKey);
+
+    const tx = await this.program.rpc.printEdition({
+      accounts: {
+        payer: _owner,
+        newMintAuthority: _owner,
+        newMint: newMint.publicKey,
+        newMetadata,
+        newEdition,
+        newMetadataUpdateAuthority: newUpdateAuthority,
+        editionMarker: await this.getEditionMarker(masterMint, edition),
+        masterEdition,
+        masterMetadata: await this.getMetadata(masterMint),
+        masterMint,
+        tokenAccountOwner: _owner,
+        tokenAccount: await this.findAssociatedTokenAddress(_owner, masterMint),
+        tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+        rent: SYSVAR_RENT_PUBKEY,
+        unpaused: await this.unpausedAccounts(),
+      },
       preInstructions: await this.generateMintInstructions(
-        mint.publicKey,
-        _payer,
-        _payer,
-        _payer,
-        _payer
+        newMint.publicKey,
+        _owner,
+        _owner,
+        _owner,
+        _owner
       ),
-      signers: [...generateMetadataAccounts.signers, mint],
+      signers: [...signerInfo.signers, newMint],
     });
 
     return {
       tx,
-      mint: mint.publicKey,
-      metadata: generateMetadataAccounts.accounts.metadata,
-      masterEdition: metadataInfo.supply
-        ? generateMetadataAccounts.accounts.masterEdition
-        : undefined,
+      edition,
+      mint: newMint.publicKey,
+      metadata: newMetadata,
+      newEdition,
     };
   };
 
@@ -236,26 +566,235 @@ export class MetadataWrapperClient extends AccountUtils {
         creator: signerInfo.payer,
         metadata,
         tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
+        unpaused: await this.unpausedAccounts(),
+      },
+      signers: signerInfo.signers,
+    });
+  };
+
+  verifyCollectionAccounts = async (
+    mint: PublicKey,
+    collectionMint: PublicKey,
+    collectionAuthority: PublicKey | Keypair
+  ) => {
+    const signerInfo = getSignersFromPayer(collectionAuthority);
+
+    return {
+      accounts: {
+        payer: signerInfo.payer,
+        collectionAuthority: signerInfo.payer,
+        metadata: await this.getMetadata(mint),
+        collectionMint,
+        collectionMetadata: await this.getMetadata(collectionMint),
+        collectionMasterEdition: await this.getMasterEdition(collectionMint),
+        tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
+        unpaused: await this.unpausedAccounts(),
+      },
+      signers: signerInfo.signers,
+    };
+  };
+
+  verifyCollection = async (
+    mint: PublicKey,
+    collectionMint: PublicKey,
+    collectionAuthority: PublicKey | Keypair
+  ) => {
+    const { accounts, signers } = await this.verifyCollectionAccounts(
+      mint,
+      collectionMint,
+      collectionAuthority
+    );
+
+    return this.program.rpc.verifyCollection({ accounts, signers });
+  };
+
+  unverifyCollection = async (
+    mint: PublicKey,
+    collectionMint: PublicKey,
+    collectionAuthority: PublicKey | Keypair
+  ) => {
+    const { accounts, signers } = await this.verifyCollectionAccounts(
+      mint,
+      collectionMint,
+      collectionAuthority
+    );
+
+    return this.program.rpc.unverifyCollection({ accounts, signers });
+  };
+
+  // metadata and collection must share the same update authority
+  setAndVerifyCollection = async (
+    mint: PublicKey,
+    collectionMint: PublicKey,
+    updateAuthority: PublicKey | Keypair
+  ) => {
+    const { accounts, signers } = await this.verifyCollectionAccounts(
+      mint,
+      collectionMint,
+      updateAuthority
+    );
+
+    return this.program.rpc.setAndVerifyCollection({
+      accounts: {
+        ...accounts,
+        updateAuthority: accounts.collectionAuthority,
+      },
+      signers,
+    });
+  };
+
+  // burns the token and closes the token account, metadata and master edition. pass the
+  // collection mint for items of a verified collection.
+  burnNft = async (
+    mint: PublicKey,
+    receiver: PublicKey,
+    owner: PublicKey | Keypair,
+    collectionMint: PublicKey = null
+  ) => {
+    const signerInfo = getSignersFromPayer(owner);
+
+    return this.program.rpc.burnNft({
+      accounts: {
+        owner: signerInfo.payer,
+        metadata: await this.getMetadata(mint),
+        mint,
+        tokenAccount: await this.findAssociatedTokenAddress(
+          signerInfo.payer,
+          mint
+        ),
+        masterEdition: await this.getMasterEdition(mint),
+        receiver,
+        tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+        unpaused: await this.unpausedAccounts(),
+      },
+      remainingAccounts: collectionMint
+        ? [
+            {
+              pubkey: await this.getMetadata(collectionMint),
+              isWritable: true,
+              isSigner: false,
+            },
+          ]
+        : [],
+      signers: signerInfo.signers,
+    });
+  };
+
+  // the use authority is either the owner of the token or a delegate approved by the owner
+  utilize = async (
+    mint: PublicKey,
+    numberOfUses: number,
+    owner: PublicKey,
+    useAuthority: PublicKey | Keypair
+  ) => {
+    const signerInfo = getSignersFromPayer(useAuthority);
+
+    return this.program.rpc.utilize(new BN(numberOfUses), {
+      accounts: {
+        useAuthority: signerInfo.payer,
+        owner,
+        metadata: await this.getMetadata(mint),
+        mint,
+        tokenAccount: await this.findAssociatedTokenAddress(owner, mint),
+        useAuthorityRecord: await this.getUseAuthorityRecord(
+          mint,
+          signerInfo.payer
+        ),
+        burner: await this.getBurner(),
+        tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+        rent: SYSVAR_RENT_PUBKEY,
+        unpaused: await this.unpausedAccounts(),
+      },
+      signers: signerInfo.signers,
+    });
+  };
+
+  useAuthorityAccounts = async (
+    mint: PublicKey,
+    user: PublicKey,
+    owner: PublicKey | Keypair
+  ) => {
+    const signerInfo = getSignersFromPayer(owner);
+
+    return {
+      accounts: {
+        owner: signerInfo.payer,
+        payer: signerInfo.payer,
+        user,
+        ownerTokenAccount: await this.findAssociatedTokenAddress(
+          signerInfo.payer,
+          mint
+        ),
+        metadata: await this.getMetadata(mint),
+        mint,
+        useAuthorityRecord: await this.getUseAuthorityRecord(mint, user),
+        burner: await this.getBurner(),
+        tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+        rent: SYSVAR_RENT_PUBKEY,
+        unpaused: await this.unpausedAccounts(),
       },
       signers: signerInfo.signers,
+    };
+  };
+
+  approveUseAuthority = async (
+    mint: PublicKey,
+    user: PublicKey,
+    numberOfUses: number,
+    owner: PublicKey | Keypair
+  ) => {
+    const { accounts, signers } = await this.useAuthorityAccounts(
+      mint,
+      user,
+      owner
+    );
+
+    return this.program.rpc.approveUseAuthority(new BN(numberOfUses), {
+      accounts,
+      signers,
     });
   };
 
+  revokeUseAuthority = async (
+    mint: PublicKey,
+    user: PublicKey,
+    owner: PublicKey | Keypair
+  ) => {
+    const { accounts, signers } = await this.useAuthorityAccounts(
+      mint,
+      user,
+      owner
+    );
+
+    return this.program.rpc.revokeUseAuthority({ accounts, signers });
+  };
+
+  // without a content hash, a hash stored for the previous uri is cleared
   updateMetadataUri = async (
     mint: PublicKey,
     uri: string,
-    payer: PublicKey | Keypair
+    payer: PublicKey | Keypair,
+    contentHash: number[] | null = null
   ) => {
     const signerInfo = getSignersFromPayer(payer);
     const metadata = await this.getMetadata(mint);
 
-    return this.program.rpc.updateMetadataUri(uri, {
+    return this.program.rpc.updateMetadataUri(uri, contentHash, {
       accounts: {
         updateAuthority: signerInfo.payer,
         metadata,
         tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
+        unpaused: await this.unpausedAccounts(),
       },
       signers: signerInfo.signers,
+      remainingAccounts: await this.updateRemainingAccounts(mint),
     });
   };
 
@@ -267,13 +806,1114 @@ export class MetadataWrapperClient extends AccountUtils {
     const signerInfo = getSignersFromPayer(payer);
     const metadata = await this.getMetadata(mint);
 
-    return this.program.rpc.updateMetadata(updateMetadataInfo as any, {
+    return this.program.rpc.updateMetadata(
+      { contentHash: null, ...updateMetadataInfo } as any,
+      {
+        accounts: {
+          updateAuthority: signerInfo.payer,
+          metadata,
+          tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
+          unpaused: await this.unpausedAccounts(),
+        },
+        signers: signerInfo.signers,
+        remainingAccounts: await this.updateRemainingAccounts(mint),
+      }
+    );
+  };
+
+  patchMetadata = async (
+    mint: PublicKey,
+    patchMetadataInfo: PatchMetadataInfo,
+    payer: PublicKey | Keypair
+  ) => {
+    const signerInfo = getSignersFromPayer(payer);
+    const metadata = await this.getMetadata(mint);
+
+    return this.program.rpc.patchMetadata(
+      {
+        name: null,
+        symbol: null,
+        uri: null,
+        sellerFeeBasisPoints: null,
+        creators: null,
+        collection: null,
+        uses: null,
+        contentHash: null,
+        ...patchMetadataInfo,
+      } as any,
+      {
+        accounts: {
+          updateAuthority: signerInfo.payer,
+          metadata,
+          tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
+          unpaused: await this.unpausedAccounts(),
+        },
+        signers: signerInfo.signers,
+        remainingAccounts: await this.updateRemainingAccounts(mint),
+      }
+    );
+  };
+
+  // first step of an update authority transfer, signed by the current update authority
+  proposeUpdateAuthority = async (
+    mint: PublicKey,
+    newUpdateAuthority: PublicKey,
+    updateAuthority: PublicKey | Keypair
+  ) => {
+    const signerInfo = getSignersFromPayer(updateAuthority);
+    const [pendingUpdateAuthority, bump] = await this.getPendingUpdateAuthority(
+      mint
+    );
+
+    return this.program.rpc.proposeUpdateAuthority(newUpdateAuthority, bump, {
       accounts: {
         updateAuthority: signerInfo.payer,
-        metadata,
+        metadata: await this.getMetadata(mint),
+        pendingUpdateAuthority,
+        tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+        unpaused: await this.unpausedAccounts(),
+      },
+      signers: signerInfo.signers,
+    });
+  };
+
+  // second step of an update authority transfer; only the new authority signs
+  acceptUpdateAuthority = async (
+    mint: PublicKey,
+    newUpdateAuthority: PublicKey | Keypair
+  ) => {
+    const signerInfo = getSignersFromPayer(newUpdateAuthority);
+    const [pendingUpdateAuthority] = await this.getPendingUpdateAuthority(mint);
+    const { updateAuthority } =
+      await this.program.account.pendingUpdateAuthority.fetch(
+        pendingUpdateAuthority
+      );
+
+    return this.program.rpc.acceptUpdateAuthority({
+      accounts: {
+        newUpdateAuthority: signerInfo.payer,
+        updateAuthority,
+        metadata: await this.getMetadata(mint),
+        pendingUpdateAuthority,
+        tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
+        unpaused: await this.unpausedAccounts(),
+      },
+      signers: signerInfo.signers,
+      remainingAccounts: await this.updateRemainingAccounts(mint),
+    });
+  };
+
+  // proposing authority gets the metadata back
+  cancelUpdateAuthorityTransfer = async (
+    mint: PublicKey,
+    updateAuthority: PublicKey | Keypair
+  ) => {
+    const signerInfo = getSignersFromPayer(updateAuthority);
+    const [pendingUpdateAuthority] = await this.getPendingUpdateAuthority(mint);
+
+    return this.program.rpc.cancelUpdateAuthorityTransfer({
+      accounts: {
+        updateAuthority: signerInfo.payer,
+        metadata: await this.getMetadata(mint),
+        pendingUpdateAuthority,
         tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
+        unpaused: await this.unpausedAccounts(),
       },
       signers: signerInfo.signers,
     });
   };
+
+  // authority is the update authority of the collection nft and starts out as the vault authority
+  initializeAuthorityVault = async (
+    collection: PublicKey,
+    authority: Keypair,
+    payer: PublicKey | Keypair
+  ) => {
+    const signerInfo = getSignersFromPayer(payer);
+    const [vault, bump] = await this.getAuthorityVault(collection);
+
+    const tx = await this.program.rpc.initializeAuthorityVault(
+      collection,
+      bump,
+      {
+        accounts: {
+          payer: signerInfo.payer,
+          authority: authority.publicKey,
+          collectionMetadata: await this.getMetadata(collection),
+          vault,
+          systemProgram: SystemProgram.programId,
+          unpaused: await this.unpausedAccounts(),
+        },
+        signers: [...signerInfo.signers, authority],
+      }
+    );
+
+    return { tx, vault };
+  };
+
+  setVaultAuthority = async (
+    collection: PublicKey,
+    newAuthority: PublicKey,
+    authority: PublicKey | Keypair
+  ) => {
+    const signerInfo = getSignersFromPayer(authority);
+    const [vault] = await this.getAuthorityVault(collection);
+
+    return this.program.rpc.setVaultAuthority(newAuthority, {
+      accounts: {
+        authority: signerInfo.payer,
+        vault,
+        unpaused: await this.unpausedAccounts(),
+      },
+      signers: signerInfo.signers,
+    });
+  };
+
+  vaultUpdateMetadataAccounts = async (
+    mint: PublicKey,
+    collection: PublicKey,
+    authority: PublicKey
+  ) => {
+    const [vault] = await this.getAuthorityVault(collection);
+
+    return {
+      authority,
+      vault,
+      metadata: await this.getMetadata(mint),
+      tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
+      unpaused: await this.unpausedAccounts(),
+    };
+  };
+
+  // verifies metadata into a collection whose collection nft is held by the vault of vaultCollection
+  vaultVerifyCollectionAccounts = async (
+    mint: PublicKey,
+    collectionMint: PublicKey,
+    vaultCollection: PublicKey,
+    authority: PublicKey | Keypair,
+    payer: PublicKey | Keypair
+  ) => {
+    const authorityInfo = getSignersFromPayer(authority);
+    const payerInfo = getSignersFromPayer(payer);
+    const [vault] = await this.getAuthorityVault(vaultCollection);
+
+    return {
+      accounts: {
+        payer: payerInfo.payer,
+        authority: authorityInfo.payer,
+        vault,
+        metadata: await this.getMetadata(mint),
+        collectionMint,
+        collectionMetadata: await this.getMetadata(collectionMint),
+        collectionMasterEdition: await this.getMasterEdition(collectionMint),
+        tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
+        unpaused: await this.unpausedAccounts(),
+      },
+      signers: [...payerInfo.signers, ...authorityInfo.signers],
+    };
+  };
+
+  vaultVerifyCollection = async (
+    mint: PublicKey,
+    collectionMint: PublicKey,
+    vaultCollection: PublicKey,
+    authority: PublicKey | Keypair,
+    payer: PublicKey | Keypair
+  ) => {
+    const { accounts, signers } = await this.vaultVerifyCollectionAccounts(
+      mint,
+      collectionMint,
+      vaultCollection,
+      authority,
+      payer
+    );
+
+    return this.program.rpc.vaultVerifyCollection({ accounts, signers });
+  };
+
+  vaultUnverifyCollection = async (
+    mint: PublicKey,
+    collectionMint: PublicKey,
+    vaultCollection: PublicKey,
+    authority: PublicKey | Keypair,
+    payer: PublicKey | Keypair
+  ) => {
+    const { accounts, signers } = await this.vaultVerifyCollectionAccounts(
+      mint,
+      collectionMint,
+      vaultCollection,
+      authority,
+      payer
+    );
+
+    return this.program.rpc.vaultUnverifyCollection({ accounts, signers });
+  };
+
+  vaultUpdateMetadataUri = async (
+    mint: PublicKey,
+    collection: PublicKey,
+    uri: string,
+    authority: PublicKey | Keypair,
+    contentHash: number[] | null = null
+  ) => {
+    const signerInfo = getSignersFromPayer(authority);
+
+    return this.program.rpc.vaultUpdateMetadataUri(uri, contentHash, {
+      accounts: await this.vaultUpdateMetadataAccounts(
+        mint,
+        collection,
+        signerInfo.payer
+      ),
+      signers: signerInfo.signers,
+      remainingAccounts: await this.updateRemainingAccounts(mint),
+    });
+  };
+
+  vaultUpdateMetadata = async (
+    mint: PublicKey,
+    collection: PublicKey,
+    updateMetadataInfo: UpdateMetadataInfo,
+    authority: PublicKey | Keypair
+  ) => {
+    const signerInfo = getSignersFromPayer(authority);
+
+    return this.program.rpc.vaultUpdateMetadata(
+      { contentHash: null, ...updateMetadataInfo } as any,
+      {
+        accounts: await this.vaultUpdateMetadataAccounts(
+          mint,
+          collection,
+          signerInfo.payer
+        ),
+        signers: signerInfo.signers,
+        remainingAccounts: await this.updateRemainingAccounts(mint),
+      }
+    );
+  };
+
+  vaultPatchMetadata = async (
+    mint: PublicKey,
+    collection: PublicKey,
+    patchMetadataInfo: PatchMetadataInfo,
+    authority: PublicKey | Keypair
+  ) => {
+    const signerInfo = getSignersFromPayer(authority);
+
+    return this.program.rpc.vaultPatchMetadata(
+      {
+        name: null,
+        symbol: null,
+        uri: null,
+        sellerFeeBasisPoints: null,
+        creators: null,
+        collection: null,
+        uses: null,
+        contentHash: null,
+        ...patchMetadataInfo,
+      } as any,
+      {
+        accounts: await this.vaultUpdateMetadataAccounts(
+          mint,
+          collection,
+          signerInfo.payer
+        ),
+        signers: signerInfo.signers,
+        remainingAccounts: await this.updateRemainingAccounts(mint),
+      }
+    );
+  };
+
+  vaultReleaseUpdateAuthority = async (
+    mint: PublicKey,
+    collection: PublicKey,
+    newUpdateAuthority: PublicKey,
+    authority: PublicKey | Keypair
+  ) => {
+    const signerInfo = getSignersFromPayer(authority);
+
+    return this.program.rpc.vaultReleaseUpdateAuthority(newUpdateAuthority, {
+      accounts: await this.vaultUpdateMetadataAccounts(
+        mint,
+        collection,
+        signerInfo.payer
+      ),
+      signers: signerInfo.signers,
+      remainingAccounts: await this.updateRemainingAccounts(mint),
+    });
+  };
+
+  // base is any fresh keypair; it's only used to derive the multisig address
+  createMultisig = async (
+    owners: PublicKey[],
+    threshold: number,
+    payer: PublicKey | Keypair,
+    base = Keypair.generate()
+  ) => {
+    const signerInfo = getSignersFromPayer(payer);
+    const [multisig, bump] = await this.getMultisig(base.publicKey);
+
+    const tx = await this.program.rpc.createMultisig(owners, threshold, bump, {
+      accounts: {
+        payer: signerInfo.payer,
+        base: base.publicKey,
+        multisig,
+        systemProgram: SystemProgram.programId,
+        unpaused: await this.unpausedAccounts(),
+      },
+      signers: [...signerInfo.signers, base],
+    });
+
+    return { tx, multisig };
+  };
+
+  proposeMultisigUpdate = async (
+    multisig: PublicKey,
+    mint: PublicKey,
+    change: QueuedMetadataChange,
+    proposer: PublicKey | Keypair
+  ) => {
+    const signerInfo = getSignersFromPayer(proposer);
+    const { proposalCount } = await this.program.account.multisig.fetch(
+      multisig
+    );
+    const proposal = await this.getMultisigProposal(multisig, proposalCount);
+
+    const tx = await this.program.rpc.proposeMultisigUpdate(change as any, {
+      accounts: {
+        proposer: signerInfo.payer,
+        multisig,
+        metadata: await this.getMetadata(mint),
+        proposal,
+        systemProgram: SystemProgram.programId,
+        unpaused: await this.unpausedAccounts(),
+      },
+      signers: signerInfo.signers,
+    });
+
+    return { tx, proposal };
+  };
+
+  approveMultisigUpdate = async (
+    multisig: PublicKey,
+    proposal: PublicKey,
+    owner: PublicKey | Keypair
+  ) => {
+    const signerInfo = getSignersFromPayer(owner);
+
+    return this.program.rpc.approveMultisigUpdate({
+      accounts: {
+        owner: signerInfo.payer,
+        multisig,
+        proposal,
+        unpaused: await this.unpausedAccounts(),
+      },
+      signers: signerInfo.signers,
+    });
+  };
+
+  // anyone can execute a proposal once enough owners approved it
+  executeMultisigUpdate = async (multisig: PublicKey, proposal: PublicKey) => {
+    const { proposer, metadata } =
+      await this.program.account.multisigProposal.fetch(proposal);
+
+    return this.program.rpc.executeMultisigUpdate({
+      accounts: {
+        proposer,
+        multisig,
+        proposal,
+        metadata,
+        tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
+        unpaused: await this.unpausedAccounts(),
+      },
+      remainingAccounts: await this.updateRemainingAccounts(
+        new PublicKey((await this.fetchMetadata(metadata)).mint)
+      ),
+    });
+  };
+
+  // authority is the update authority of the collection nft and starts out as the timelock authority
+  initializeTimelock = async (
+    collection: PublicKey,
+    delay: number,
+    authority: Keypair,
+    payer: PublicKey | Keypair
+  ) => {
+    const signerInfo = getSignersFromPayer(payer);
+    const [timelock, bump] = await this.getTimelock(collection);
+
+    const tx = await this.program.rpc.initializeTimelock(
+      collection,
+      new BN(delay),
+      bump,
+      {
+        accounts: {
+          payer: signerInfo.payer,
+          authority: authority.publicKey,
+          collectionMetadata: await this.getMetadata(collection),
+          timelock,
+          systemProgram: SystemProgram.programId,
+          unpaused: await this.unpausedAccounts(),
+        },
+        signers: [...signerInfo.signers, authority],
+      }
+    );
+
+    return { tx, timelock };
+  };
+
+  setTimelockDelay = async (
+    collection: PublicKey,
+    delay: number,
+    authority: PublicKey | Keypair
+  ) => {
+    const signerInfo = getSignersFromPayer(authority);
+    const [timelock] = await this.getTimelock(collection);
+
+    return this.program.rpc.setTimelockDelay(new BN(delay), {
+      accounts: {
+        authority: signerInfo.payer,
+        timelock,
+        unpaused: await this.unpausedAccounts(),
+      },
+      signers: signerInfo.signers,
+    });
+  };
+
+  queueMetadataUpdate = async (
+    mint: PublicKey,
+    collection: PublicKey,
+    change: QueuedMetadataChange,
+    authority: PublicKey | Keypair
+  ) => {
+    const signerInfo = getSignersFromPayer(authority);
+    const [timelock] = await this.getTimelock(collection);
+
+    return this.program.rpc.queueMetadataUpdate(change as any, {
+      accounts: {
+        authority: signerInfo.payer,
+        timelock,
+        metadata: await this.getMetadata(mint),
+        queuedUpdate: await this.getQueuedMetadataUpdate(mint),
+        systemProgram: SystemProgram.programId,
+        unpaused: await this.unpausedAccounts(),
+      },
+      signers: signerInfo.signers,
+    });
+  };
+
+  // crank; anyone can execute a queued update once its delay passed
+  executeMetadataUpdate = async (mint: PublicKey) => {
+    const queuedUpdate = await this.getQueuedMetadataUpdate(mint);
+    const { authority, timelock, metadata } =
+      await this.program.account.queuedMetadataUpdate.fetch(queuedUpdate);
+
+    return this.program.rpc.executeMetadataUpdate({
+      accounts: {
+        authority,
+        timelock,
+        metadata,
+        queuedUpdate,
+        tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
+        unpaused: await this.unpausedAccounts(),
+      },
+      remainingAccounts: await this.updateRemainingAccounts(mint),
+    });
+  };
+
+  cancelMetadataUpdate = async (
+    mint: PublicKey,
+    collection: PublicKey,
+    authority: PublicKey | Keypair
+  ) => {
+    const signerInfo = getSignersFromPayer(authority);
+    const [timelock] = await this.getTimelock(collection);
+
+    return this.program.rpc.cancelMetadataUpdate({
+      accounts: {
+        authority: signerInfo.payer,
+        timelock,
+        queuedUpdate: await this.getQueuedMetadataUpdate(mint),
+        unpaused: await this.unpausedAccounts(),
+      },
+      signers: signerInfo.signers,
+    });
+  };
+
+  // opts a mint into on-chain revision history; anyone can pay for it
+  initializeMetadataHistory = async (
+    mint: PublicKey,
+    payer: PublicKey | Keypair
+  ) => {
+    const signerInfo = getSignersFromPayer(payer);
+    const history = await this.getMetadataHistory(mint);
+
+    const tx = await this.program.rpc.initializeMetadataHistory({
+      accounts: {
+        payer: signerInfo.payer,
+        metadata: await this.getMetadata(mint),
+        history,
+        systemProgram: SystemProgram.programId,
+        unpaused: await this.unpausedAccounts(),
+      },
+      signers: signerInfo.signers,
+    });
+
+    return { tx, history };
+  };
+
+  // anyone can pay for the content hash account of a mint, even before the mint is created
+  initializeContentHash = async (
+    mint: PublicKey,
+    payer: PublicKey | Keypair
+  ) => {
+    const signerInfo = getSignersFromPayer(payer);
+    const contentHash = await this.getContentHash(mint);
+
+    const tx = await this.program.rpc.initializeContentHash({
+      accounts: {
+        payer: signerInfo.payer,
+        mint,
+        contentHash,
+        systemProgram: SystemProgram.programId,
+        unpaused: await this.unpausedAccounts(),
+      },
+      signers: signerInfo.signers,
+    });
+
+    return { tx, contentHash };
+  };
+
+  // admin has to be the upgrade authority of the program
+  initializeConfig = async (
+    info: InitializeConfigInfo,
+    admin: PublicKey | Keypair
+  ) => {
+    const signerInfo = getSignersFromPayer(admin);
+    const [config, bump] = await this.getConfig();
+
+    const tx = await this.program.rpc.initializeConfig(
+      { feeMint: null, ...info } as any,
+      bump,
+      {
+        accounts: {
+          admin: signerInfo.payer,
+          program: this.program.programId,
+          programData: await this.getProgramData(),
+          config,
+          systemProgram: SystemProgram.programId,
+        },
+        signers: signerInfo.signers,
+      }
+    );
+
+    return { tx, config };
+  };
+
+  updateConfig = async (info: UpdateConfigInfo, admin: PublicKey | Keypair) => {
+    const signerInfo = getSignersFromPayer(admin);
+    const [config] = await this.getConfig();
+
+    return this.program.rpc.updateConfig(
+      {
+        admin: null,
+        paused: null,
+        treasury: null,
+        generateFeeLamports: null,
+        feeMint: null,
+        generateFeeTokens: null,
+        feeWaivers: null,
+        ...info,
+      } as any,
+      {
+        accounts: {
+          admin: signerInfo.payer,
+          config,
+        },
+        signers: signerInfo.signers,
+      }
+    );
+  };
+
+  initializeCollectionTemplate = async (
+    collection: PublicKey,
+    template: MetadataTemplate,
+    authority: Keypair,
+    payer: PublicKey | Keypair
+  ) => {
+    const signerInfo = getSignersFromPayer(payer);
+    const [collectionTemplate, bump] = await this.getCollectionTemplate(
+      collection
+    );
+
+    const tx = await this.program.rpc.initializeCollectionTemplate(
+      collection,
+      template as any,
+      bump,
+      {
+        accounts: {
+          payer: signerInfo.payer,
+          authority: authority.publicKey,
+          collectionMetadata: await this.getMetadata(collection),
+          template: collectionTemplate,
+          systemProgram: SystemProgram.programId,
+          unpaused: await this.unpausedAccounts(),
+        },
+        signers: [...signerInfo.signers, authority],
+      }
+    );
+
+    return { tx, template: collectionTemplate };
+  };
+
+  updateCollectionTemplate = async (
+    collection: PublicKey,
+    template: MetadataTemplate,
+    authority: PublicKey | Keypair
+  ) => {
+    const signerInfo = getSignersFromPayer(authority);
+    const [collectionTemplate] = await this.getCollectionTemplate(collection);
+
+    return this.program.rpc.updateCollectionTemplate(template as any, {
+      accounts: {
+        authority: signerInfo.payer,
+        template: collectionTemplate,
+        unpaused: await this.unpausedAccounts(),
+      },
+      signers: signerInfo.signers,
+    });
+  };
+
+  // template authority pays and signs as mint and update authority of the generated nft
+  generateMetadataFromTemplate = async (
+    mint: Keypair,
+    collection: PublicKey,
+    name: string,
+    uri: string,
+    overrides: TemplateOverrides,
+    authority: PublicKey | Keypair,
+    feePayment: FeePayment = { lamports: {} },
+    contentHash: number[] | null = null
+  ) => {
+    const generateMetadataAccounts = await this.generateMetadataAccounts(
+      mint,
+      authority
+    );
+    const [template] = await this.getCollectionTemplate(collection);
+
+    const _payer = generateMetadataAccounts.accounts.payer;
+
+    const tx = await this.program.rpc.generateFromTemplate(
+      name,
+      uri,
+      {
+        symbol: null,
+        creators: null,
+        sellerFeeBasisPoints: null,
+        isMutable: null,
+        collection: null,
+        uses: null,
+        supply: null,
+        newUpdateAuthority: null,
+        ...overrides,
+      } as any,
+      feePayment as any,
+      contentHash,
+      {
+        accounts: {
+          generate: generateMetadataAccounts.accounts,
+          template,
+        },
+        remainingAccounts: await this.generateRemainingAccounts(
+          feePayment,
+          _payer,
+          mint.publicKey
+        ),
+        preInstructions: await this.generateMintInstructions(
+          mint.publicKey,
+          _payer,
+          _payer,
+          _payer,
+          _payer
+        ),
+        signers: [...generateMetadataAccounts.signers, mint],
+      }
+    );
+
+    return {
+      tx,
+      mint: mint.publicKey,
+      metadata: generateMetadataAccounts.accounts.metadata,
+    };
+  };
+
+  // items are named "namePrefix #N" with uri "baseUri/N.json", counting from 1
+  initializeCollectionCounter = async (
+    collection: PublicKey,
+    maxSupply: number,
+    namePrefix: string,
+    baseUri: string,
+    authority: Keypair,
+    payer: PublicKey | Keypair
+  ) => {
+    const signerInfo = getSignersFromPayer(payer);
+    const [counter, bump] = await this.getCollectionCounter(collection);
+
+    const tx = await this.program.rpc.initializeCollectionCounter(
+      collection,
+      new BN(maxSupply),
+      namePrefix,
+      baseUri,
+      bump,
+      {
+        accounts: {
+          payer: signerInfo.payer,
+          authority: authority.publicKey,
+          collectionMetadata: await this.getMetadata(collection),
+          counter,
+          systemProgram: SystemProgram.programId,
+          unpaused: await this.unpausedAccounts(),
+        },
+        signers: [...signerInfo.signers, authority],
+      }
+    );
+
+    return { tx, counter };
+  };
+
+  // name and uri are numbered by the collection counter; the template has to be part of the
+  // collection
+  generateNumberedMetadata = async (
+    mint: Keypair,
+    collection: PublicKey,
+    template: MetadataTemplate,
+    authority: PublicKey | Keypair,
+    feePayment: FeePayment = { lamports: {} },
+    contentHash: number[] | null = null
+  ) => {
+    const generateMetadataAccounts = await this.generateMetadataAccounts(
+      mint,
+      authority
+    );
+    const [counter] = await this.getCollectionCounter(collection);
+
+    const _payer = generateMetadataAccounts.accounts.payer;
+
+    const tx = await this.program.rpc.generateNumbered(
+      template as any,
+      feePayment as any,
+      contentHash,
+      {
+        accounts: {
+          generate: generateMetadataAccounts.accounts,
+          counter,
+        },
+        remainingAccounts: await this.generateRemainingAccounts(
+          feePayment,
+          _payer,
+          mint.publicKey
+        ),
+        preInstructions: await this.generateMintInstructions(
+          mint.publicKey,
+          _payer,
+          _payer,
+          _payer,
+          _payer
+        ),
+        signers: [...generateMetadataAccounts.signers, mint],
+      }
+    );
+
+    return {
+      tx,
+      mint: mint.publicKey,
+      metadata: generateMetadataAccounts.accounts.metadata,
+    };
+  };
+
+  // root is the leaf of the mint, or the merkle root over the leaves of every mint of the
+  // collection; see revealLeaf and hashMerkleNodes
+  commitReveal = async (
+    key: PublicKey,
+    root: Buffer,
+    revealAt: number, // unix timestamp
+    authority: PublicKey | Keypair
+  ) => {
+    const signerInfo = getSignersFromPayer(authority);
+    const [commitment, bump] = await this.getRevealCommitment(key);
+
+    const tx = await this.program.rpc.commitReveal(
+      key,
+      Array.from(root),
+      new BN(revealAt),
+      bump,
+      {
+        accounts: {
+          authority: signerInfo.payer,
+          keyMetadata: await this.getMetadata(key),
+          commitment,
+          systemProgram: SystemProgram.programId,
+          unpaused: await this.unpausedAccounts(),
+        },
+        signers: signerInfo.signers,
+      }
+    );
+
+    return { tx, commitment };
+  };
+
+  // before reveal_at only the commitment authority can reveal, afterwards anyone can crank it.
+  // contentHash is the one committed in the leaf of the mint, see revealLeaf
+  reveal = async (
+    mint: PublicKey,
+    key: PublicKey,
+    uri: string,
+    proof: Buffer[],
+    cranker: PublicKey | Keypair,
+    contentHash: number[] | null = null
+  ) => {
+    const signerInfo = getSignersFromPayer(cranker);
+    const [commitment] = await this.getRevealCommitment(key);
+
+    return this.program.rpc.reveal(
+      uri,
+      contentHash,
+      proof.map((node) => Array.from(node)),
+      {
+        accounts: {
+          cranker: signerInfo.payer,
+          commitment,
+          metadata: await this.getMetadata(mint),
+          tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
+          unpaused: await this.unpausedAccounts(),
+        },
+        signers: signerInfo.signers,
+        remainingAccounts: await this.updateRemainingAccounts(mint),
+      }
+    );
+  };
+
+  // proof of the current uri, so that only revealed metadata can be released
+  releaseRevealAuthority = async (
+    mint: PublicKey,
+    key: PublicKey,
+    newUpdateAuthority: PublicKey,
+    proof: Buffer[],
+    authority: PublicKey | Keypair,
+    contentHash: number[] | null = null
+  ) => {
+    const signerInfo = getSignersFromPayer(authority);
+    const [commitment] = await this.getRevealCommitment(key);
+
+    return this.program.rpc.releaseRevealAuthority(
+      newUpdateAuthority,
+      contentHash,
+      proof.map((node) => Array.from(node)),
+      {
+        accounts: {
+          authority: signerInfo.payer,
+          commitment,
+          metadata: await this.getMetadata(mint),
+          tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
+          unpaused: await this.unpausedAccounts(),
+        },
+        signers: signerInfo.signers,
+        remainingAccounts: await this.updateRemainingAccounts(mint),
+      }
+    );
+  };
+
+  // root is the merkle root over the allowlist leaves; see allowlistLeaf and hashMerkleNodes
+  initializeAllowlist = async (
+    collection: PublicKey,
+    root: Buffer,
+    authority: Keypair,
+    payer: PublicKey | Keypair
+  ) => {
+    const signerInfo = getSignersFromPayer(payer);
+    const [allowlist, bump] = await this.getAllowlist(collection);
+
+    const tx = await this.program.rpc.initializeAllowlist(
+      collection,
+      Array.from(root),
+      bump,
+      {
+        accounts: {
+          payer: signerInfo.payer,
+          authority: authority.publicKey,
+          collectionMetadata: await this.getMetadata(collection),
+          allowlist,
+          systemProgram: SystemProgram.programId,
+          unpaused: await this.unpausedAccounts(),
+        },
+        signers: [...signerInfo.signers, authority],
+      }
+    );
+
+    return { tx, allowlist };
+  };
+
+  updateAllowlistRoot = async (
+    collection: PublicKey,
+    root: Buffer,
+    authority: PublicKey | Keypair
+  ) => {
+    const signerInfo = getSignersFromPayer(authority);
+    const [allowlist] = await this.getAllowlist(collection);
+
+    return this.program.rpc.updateAllowlistRoot(Array.from(root), {
+      accounts: {
+        authority: signerInfo.payer,
+        allowlist,
+        unpaused: await this.unpausedAccounts(),
+      },
+      signers: signerInfo.signers,
+    });
+  };
+
+  // payer is the allowlisted wallet and receives the nft; the allowlist signs as mint and update
+  // authority, which passes on to the allowlist authority. metadataInfo.collection has to be the
+  // allowlist's collection.
+  generateMetadataAllowlisted = async (
+    mint: Keypair,
+    metadataInfo: MetadataInfo,
+    maxMints: number,
+    proof: Buffer[],
+    payer: PublicKey | Keypair,
+    feePayment: FeePayment = { lamports: {} }
+  ) => {
+    const signerInfo = getSignersFromPayer(payer);
+    const [allowlist] = await this.getAllowlist(metadataInfo.collection.key);
+    const metadata = await this.getMetadata(mint.publicKey);
+    const tokenAccount = await this.findAssociatedTokenAddress(
+      signerInfo.payer,
+      mint.publicKey
+    );
+
+    const tx = await this.program.rpc.generateAllowlisted(
+      { contentHash: null, ...metadataInfo } as any,
+      new BN(maxMints),
+      proof.map((node) => Array.from(node)),
+      feePayment as any,
+      {
+        accounts: {
+          payer: signerInfo.payer,
+          allowlist,
+          claimStatus: await this.getClaimStatus(allowlist, signerInfo.payer),
+          treasury: await this.getTreasury(),
+          mint: mint.publicKey,
+          tokenAccount,
+          metadata,
+          masterEdition: await this.getMasterEdition(mint.publicKey),
+          tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+          rent: SYSVAR_RENT_PUBKEY,
+          unpaused: await this.unpausedAccounts(),
+        },
+        remainingAccounts: await this.generateRemainingAccounts(
+          feePayment,
+          signerInfo.payer,
+          mint.publicKey
+        ),
+        signers: [...signerInfo.signers, mint],
+      }
+    );
+
+    return { tx, mint: mint.publicKey, tokenAccount, metadata };
+  };
+
+  // items are generated from the collection template, see initializeCollectionTemplate
+  initializeDrop = async (
+    collection: PublicKey,
+    info: DropInfo,
+    authority: Keypair,
+    payer: PublicKey | Keypair
+  ) => {
+    const signerInfo = getSignersFromPayer(payer);
+    const [drop, bump] = await this.getDrop(collection);
+
+    const tx = await this.program.rpc.initializeDrop(
+      collection,
+      info as any,
+      bump,
+      {
+        accounts: {
+          payer: signerInfo.payer,
+          authority: authority.publicKey,
+          collectionMetadata: await this.getMetadata(collection),
+          drop,
+          systemProgram: SystemProgram.programId,
+          unpaused: await this.unpausedAccounts(),
+        },
+        signers: [...signerInfo.signers, authority],
+      }
+    );
+
+    return { tx, drop };
+  };
+
+  updateDrop = async (
+    collection: PublicKey,
+    info: DropInfo,
+    authority: PublicKey | Keypair
+  ) => {
+    const signerInfo = getSignersFromPayer(authority);
+    const [drop] = await this.getDrop(collection);
+
+    return this.program.rpc.updateDrop(info as any, {
+      accounts: {
+        authority: signerInfo.payer,
+        drop,
+        unpaused: await this.unpausedAccounts(),
+      },
+      signers: signerInfo.signers,
+    });
+  };
+
+  // buyer pays the price and receives the next item of the drop; no other signer is needed
+  mintFromDrop = async (
+    mint: Keypair,
+    collection: PublicKey,
+    buyer: PublicKey | Keypair,
+    feePayment: FeePayment = { lamports: {} }
+  ) => {
+    const signerInfo = getSignersFromPayer(buyer);
+    const [drop] = await this.getDrop(collection);
+    const [template] = await this.getCollectionTemplate(collection);
+    const metadata = await this.getMetadata(mint.publicKey);
+    const tokenAccount = await this.findAssociatedTokenAddress(
+      signerInfo.payer,
+      mint.publicKey
+    );
+
+    const tx = await this.program.rpc.mintFromDrop(feePayment as any, {
+      accounts: {
+        payer: signerInfo.payer,
+        drop,
+        template,
+        dropTreasury: (await this.program.account.collectionDrop.fetch(drop))
+          .treasury,
+        treasury: await this.getTreasury(),
+        mint: mint.publicKey,
+        tokenAccount,
+        metadata,
+        masterEdition: await this.getMasterEdition(mint.publicKey),
+        tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+        rent: SYSVAR_RENT_PUBKEY,
+        unpaused: await this.unpausedAccounts(),
+      },
+      remainingAccounts: await this.feeRemainingAccounts(
+        feePayment,
+        signerInfo.payer
+      ),
+      signers: [...signerInfo.signers, mint],
+    });
+
+    return { tx, mint: mint.publicKey, tokenAccount, metadata };
+  };
 }
@@ -7,7 +7,7 @@ import {
   Connection,
   LAMPORTS_PER_SOL,
 } from "@solana/web3.js";
-import { u64 } from "@solana/spl-token";
+import { u64, Token, TOKEN_PROGRAM_ID } from "@solana/spl-token";
 
 import {
   MetadataWrapperClient,
@@ -18,10 +18,15 @@ import {
   MasterEditionV1,
   MasterEditionV2,
   Metadata,
-  MetadataCreator
+  MetadataCreator,
+  revealLeaf,
+  hashMerkleNodes,
+  allowlistLeaf,
+  hashContent,
+  TOKEN_METADATA_PROGRAM_ID,
 } from "../sdk";
 
-import { expectThrowsAsync } from "./util";
+import { expectProgramErrorAsync, expectThrowsAsync, sleep } from "./util";
 
 export const generateDefaultCreators = (
   authority: PublicKey,
@@ -59,7 +64,10 @@ export const generateMetadata = (
     updateAuthorityIsSigner: true,
     isMutable: true,
     creators,
-    supply: supply !== null ? new u64(supply) : null,
+    supply:
+      supply !== null
+        ? { limited: { maxSupply: new u64(supply) } }
+        : { none: {} },
     collection: null,
     uses: null,
     newUpdateAuthority,
@@ -155,6 +163,20 @@ describe("metadata_wrapper", () => {
     defaultCreators = generateDefaultCreators(user.publicKey, otherCreator);
   });
 
+  // the provider wallet deploys the program, so it's the upgrade authority that can create the
+  // config. needs the program deployed through the upgradeable loader.
+  before("initialize config", async () => {
+    await client.initializeConfig(
+      {
+        treasury: _provider.wallet.publicKey,
+        generateFeeLamports: new anchor.BN(0),
+        generateFeeTokens: new anchor.BN(0),
+        feeWaivers: [],
+      },
+      _provider.wallet.publicKey
+    );
+  });
+
   it("Generate metadata happy path => metadata, master edition, primary sale happened", async () => {
     const name = "NAMO";
     const symbol = "SYMB";
@@ -351,4 +373,1520 @@ describe("metadata_wrapper", () => {
     // no master edition exists
     expect(masterEdition === undefined).to.be.true;
   });
+
+  it("Generate metadata with mint => mint, recipient token account, metadata, master edition", async () => {
+    const name = "name3";
+    const symbol = "symbol3";
+    const uri = "uri3";
+    const maxSupply = 0;
+
+    const metadataInfo = generateMetadata(
+      name,
+      symbol,
+      uri,
+      maxSupply,
+      user.publicKey,
+      defaultCreators
+    );
+
+    const mint = Keypair.generate();
+
+    const { tokenAccount, metadata, masterEdition } =
+      await client.generateMetadataWithMint(
+        mint,
+        rando.publicKey,
+        metadataInfo,
+        user
+      );
+
+    const balance = await client.connection.getTokenAccountBalance(
+      tokenAccount
+    );
+    expect(balance.value.amount).to.be.equal("1");
+
+    const _metadata = await client.fetchMetadata(metadata);
+    validateMetadata(_metadata, {
+      name,
+      symbol,
+      primarySaleHappened: true,
+      updateAuthority: user.publicKey,
+      isMutable: true,
+    });
+
+    const _masterEdition = await client.fetchMasterEdition(masterEdition);
+    validateMasterEdition(_masterEdition, maxSupply);
+  });
+
+  it("Master edition owner can print numbered editions up to max supply", async () => {
+    const maxSupply = 1;
+    const masterMint = Keypair.generate();
+    await client.generateMetadata(
+      masterMint,
+      generateMetadata(
+        "master",
+        "MSTR",
+        "uri4",
+        maxSupply,
+        user.publicKey,
+        defaultCreators
+      ),
+      user
+    );
+
+    const { edition, metadata } = await client.printEdition(
+      masterMint.publicKey,
+      Keypair.generate(),
+      user.publicKey,
+      user
+    );
+    expect(edition).to.be.equal(1);
+    expect(await accountExists(client.connection, metadata)).to.be.true;
+
+    const _masterEdition = await client.fetchMasterEdition(
+      await client.getMasterEdition(masterMint.publicKey)
+    );
+    expect(_masterEdition.supply.toNumber()).to.be.equal(1);
+
+    await expectThrowsAsync(() =>
+      client.printEdition(
+        masterMint.publicKey,
+        Keypair.generate(),
+        user.publicKey,
+        user
+      )
+    );
+  });
+
+  it("Collection update authority can verify, unverify, and set and verify a collection", async () => {
+    const collectionMint = Keypair.generate();
+    await client.generateMetadata(
+      collectionMint,
+      generateMetadata(
+        "collection",
+        "COLL",
+        "uri5",
+        0,
+        user.publicKey,
+        defaultCreators
+      ),
+      user
+    );
+
+    const metadataInfo = generateMetadata(
+      "item",
+      "COLL",
+      "uri6",
+      0,
+      user.publicKey,
+      defaultCreators
+    );
+    metadataInfo.collection = {
+      verified: false,
+      key: collectionMint.publicKey,
+    };
+    const mint = Keypair.generate();
+    const { metadata } = await client.generateMetadata(
+      mint,
+      metadataInfo,
+      user
+    );
+
+    await expectThrowsAsync(() =>
+      client.verifyCollection(
+        mint.publicKey,
+        collectionMint.publicKey,
+        rando
+      )
+    );
+
+    await client.verifyCollection(
+      mint.publicKey,
+      collectionMint.publicKey,
+      user
+    );
+    expect(!!(await client.fetchMetadata(metadata)).collection.verified).to.be
+      .true;
+
+    await client.unverifyCollection(
+      mint.publicKey,
+      collectionMint.publicKey,
+      user
+    );
+    expect(!!(await client.fetchMetadata(metadata)).collection.verified).to.be
+      .false;
+
+    const otherMint = Keypair.generate();
+    const { metadata: otherMetadata } = await client.generateMetadata(
+      otherMint,
+      generateMetadata(
+        "other item",
+        "COLL",
+        "uri7",
+        0,
+        user.publicKey,
+        defaultCreators
+      ),
+      user
+    );
+    await client.setAndVerifyCollection(
+      otherMint.publicKey,
+      collectionMint.publicKey,
+      user
+    );
+    const _otherMetadata = await client.fetchMetadata(otherMetadata);
+    expect(_otherMetadata.collection.key).to.be.equal(
+      collectionMint.publicKey.toBase58()
+    );
+    expect(!!_otherMetadata.collection.verified).to.be.true;
+  });
+
+  it("Owner can burn nft, rent of token account, metadata and master edition goes to receiver", async () => {
+    const mint = Keypair.generate();
+    const { metadata, masterEdition } = await client.generateMetadata(
+      mint,
+      generateMetadata(
+        "burn",
+        "BURN",
+        "uri8",
+        0,
+        user.publicKey,
+        defaultCreators
+      ),
+      user
+    );
+
+    await expectThrowsAsync(() =>
+      client.burnNft(mint.publicKey, rando.publicKey, rando)
+    );
+
+    const receiver = Keypair.generate().publicKey;
+    await client.burnNft(mint.publicKey, receiver, user);
+
+    expect(await client.connection.getAccountInfo(metadata)).to.be.null;
+    expect(await client.connection.getAccountInfo(masterEdition)).to.be.null;
+    expect(await client.connection.getBalance(receiver)).to.be.greaterThan(0);
+  });
+
+  it("Owner and approved use authority can utilize nft, revoked use authority cannot", async () => {
+    const metadataInfo = generateMetadata(
+      "usable",
+      "USE",
+      "uri9",
+      0,
+      user.publicKey,
+      defaultCreators
+    );
+    metadataInfo.uses = {
+      useMethod: { multiple: {} },
+      remaining: new u64(5),
+      total: new u64(5),
+    };
+    const mint = Keypair.generate();
+    const { metadata } = await client.generateMetadata(
+      mint,
+      metadataInfo,
+      user
+    );
+
+    await client.utilize(mint.publicKey, 1, user.publicKey, user);
+    expect(
+      (await client.fetchMetadata(metadata)).uses.remaining.toNumber()
+    ).to.be.equal(4);
+
+    await expectThrowsAsync(() =>
+      client.utilize(mint.publicKey, 1, user.publicKey, rando)
+    );
+
+    await client.approveUseAuthority(mint.publicKey, rando.publicKey, 2, user);
+    await client.utilize(mint.publicKey, 1, user.publicKey, rando);
+    expect(
+      (await client.fetchMetadata(metadata)).uses.remaining.toNumber()
+    ).to.be.equal(3);
+
+    await client.revokeUseAuthority(mint.publicKey, rando.publicKey, user);
+    await expectThrowsAsync(() =>
+      client.utilize(mint.publicKey, 1, user.publicKey, rando)
+    );
+  });
+
+  it("Update authority can patch single metadata fields, others are kept", async () => {
+    const mint = Keypair.generate();
+    const { metadata } = await client.generateMetadata(
+      mint,
+      generateMetadata(
+        "patch",
+        "PTCH",
+        "uri10",
+        0,
+        user.publicKey,
+        defaultCreators
+      ),
+      user
+    );
+
+    await expectThrowsAsync(() =>
+      client.patchMetadata(mint.publicKey, { uri: "uri11" }, rando)
+    );
+
+    await client.patchMetadata(mint.publicKey, { uri: "uri11" }, user);
+
+    const _metadata = await client.fetchMetadata(metadata);
+    expect(_metadata.data.uri).to.be.equal("uri11");
+    expect(_metadata.data.name).to.be.equal("patch");
+    expect(_metadata.data.symbol).to.be.equal("PTCH");
+    expect(_metadata.data.sellerFeeBasisPoints).to.be.equal(420);
+    expect(_metadata.data.creators.length).to.be.equal(2);
+  });
+
+  it("Generate rejects metadata over the metaplex limits", async () => {
+    const metadataInfo = generateMetadata(
+      "n".repeat(33),
+      "SYMB",
+      "uri12",
+      0,
+      user.publicKey,
+      defaultCreators
+    );
+
+    await expectThrowsAsync(() =>
+      client.generateMetadata(Keypair.generate(), metadataInfo, user)
+    );
+  });
+
+  it("Update authority changing the URI emits MetadataUriUpdated", async () => {
+    const mint = Keypair.generate();
+    await client.generateMetadata(
+      mint,
+      generateMetadata(
+        "events",
+        "EVNT",
+        "uri13",
+        0,
+        user.publicKey,
+        defaultCreators
+      ),
+      user
+    );
+
+    let event: any = null;
+    const listener = client.program.addEventListener(
+      "MetadataUriUpdated",
+      (e) => {
+        event = e;
+      }
+    );
+
+    await client.updateMetadataUri(mint.publicKey, "uri14", user);
+    await new Promise((resolve) => setTimeout(resolve, 1000));
+    await client.program.removeEventListener(listener);
+
+    expect(event.mint.toBase58()).to.be.equal(mint.publicKey.toBase58());
+    expect(event.oldUri).to.be.equal("uri13");
+    expect(event.newUri).to.be.equal("uri14");
+  });
+
+  it("Generate metadata with unlimited master edition supply", async () => {
+    const metadataInfo = generateMetadata(
+      "unlimited",
+      "UNLM",
+      "uri15",
+      null,
+      user.publicKey,
+      defaultCreators
+    );
+    metadataInfo.supply = { unlimited: {} };
+
+    const { masterEdition } = await client.generateMetadata(
+      Keypair.generate(),
+      metadataInfo,
+      user
+    );
+
+    const _masterEdition = await client.fetchMasterEdition(masterEdition);
+    expect(_masterEdition.maxSupply).to.be.undefined;
+  });
+
+  it("Update authority transfer only completes once the proposed authority accepts", async () => {
+    const mint = Keypair.generate();
+    const { metadata } = await client.generateMetadata(
+      mint,
+      generateMetadata(
+        "transfer",
+        "TRNS",
+        "uri16",
+        0,
+        user.publicKey,
+        defaultCreators
+      ),
+      user
+    );
+
+    await client.proposeUpdateAuthority(mint.publicKey, rando.publicKey, user);
+    // an open proposal blocks a second one until it's cancelled
+    await expectThrowsAsync(() =>
+      client.proposeUpdateAuthority(
+        mint.publicKey,
+        otherCreator.publicKey,
+        user
+      )
+    );
+    // only the proposing authority can cancel, and gets the metadata back
+    await expectThrowsAsync(() =>
+      client.cancelUpdateAuthorityTransfer(mint.publicKey, rando)
+    );
+    await client.cancelUpdateAuthorityTransfer(mint.publicKey, user);
+    expect((await client.fetchMetadata(metadata)).updateAuthority).to.be.equal(
+      user.publicKey.toBase58()
+    );
+
+    await client.proposeUpdateAuthority(
+      mint.publicKey,
+      otherCreator.publicKey,
+      user
+    );
+    // the pending transfer holds the metadata until it's accepted
+    const [pendingUpdateAuthority] = await client.getPendingUpdateAuthority(
+      mint.publicKey
+    );
+    expect((await client.fetchMetadata(metadata)).updateAuthority).to.be.equal(
+      pendingUpdateAuthority.toBase58()
+    );
+    await expectThrowsAsync(() =>
+      client.updateMetadataUri(mint.publicKey, "uri16b", user)
+    );
+
+    // only the proposed authority can accept, without the proposing authority signing again
+    await expectThrowsAsync(() =>
+      client.acceptUpdateAuthority(mint.publicKey, rando)
+    );
+
+    await client.acceptUpdateAuthority(mint.publicKey, otherCreator);
+    expect((await client.fetchMetadata(metadata)).updateAuthority).to.be.equal(
+      otherCreator.publicKey.toBase58()
+    );
+  });
+
+  it("Metadata held by an authority vault only changes with the vault authority's approval", async () => {
+    const collectionMint = Keypair.generate();
+    await client.generateMetadata(
+      collectionMint,
+      generateMetadata("vault", "VLT", "uri17", 0, user.publicKey),
+      user
+    );
+
+    // only the collection's update authority can create its vault
+    await expectThrowsAsync(() =>
+      client.initializeAuthorityVault(collectionMint.publicKey, rando, user)
+    );
+    const { vault } = await client.initializeAuthorityVault(
+      collectionMint.publicKey,
+      user,
+      user
+    );
+
+    const mint = Keypair.generate();
+    const { metadata } = await client.generateMetadata(
+      mint,
+      generateMetadata(
+        "vaulted",
+        "VLT",
+        "uri18",
+        0,
+        user.publicKey,
+        defaultCreators,
+        vault
+      ),
+      user
+    );
+    expect((await client.fetchMetadata(metadata)).updateAuthority).to.be.equal(
+      vault.toBase58()
+    );
+
+    await client.vaultUpdateMetadataUri(
+      mint.publicKey,
+      collectionMint.publicKey,
+      "uri19",
+      user
+    );
+    expect((await client.fetchMetadata(metadata)).data.uri).to.be.equal(
+      "uri19"
+    );
+    await expectThrowsAsync(() =>
+      client.vaultUpdateMetadataUri(
+        mint.publicKey,
+        collectionMint.publicKey,
+        "uri20",
+        rando
+      )
+    );
+
+    await client.setVaultAuthority(
+      collectionMint.publicKey,
+      otherCreator.publicKey,
+      user
+    );
+    await client.vaultPatchMetadata(
+      mint.publicKey,
+      collectionMint.publicKey,
+      { name: "patched" },
+      otherCreator
+    );
+    expect((await client.fetchMetadata(metadata)).data.name).to.be.equal(
+      "patched"
+    );
+
+    await client.vaultReleaseUpdateAuthority(
+      mint.publicKey,
+      collectionMint.publicKey,
+      user.publicKey,
+      otherCreator
+    );
+    expect((await client.fetchMetadata(metadata)).updateAuthority).to.be.equal(
+      user.publicKey.toBase58()
+    );
+
+    // the vault signs as collection authority of a collection nft it holds
+    const vaultedCollection = Keypair.generate();
+    await client.generateMetadata(
+      vaultedCollection,
+      generateMetadata(
+        "vaulted collection",
+        "VLT",
+        "uri21",
+        0,
+        user.publicKey,
+        defaultCreators,
+        vault
+      ),
+      user
+    );
+    const item = Keypair.generate();
+    const { metadata: itemMetadata } = await client.generateMetadata(
+      item,
+      {
+        ...generateMetadata("vaulted item", "VLT", "uri21", 0, user.publicKey),
+        collection: { verified: false, key: vaultedCollection.publicKey },
+      },
+      user
+    );
+    await expectThrowsAsync(() =>
+      client.vaultVerifyCollection(
+        item.publicKey,
+        vaultedCollection.publicKey,
+        collectionMint.publicKey,
+        user,
+        user
+      )
+    );
+    await client.vaultVerifyCollection(
+      item.publicKey,
+      vaultedCollection.publicKey,
+      collectionMint.publicKey,
+      otherCreator,
+      user
+    );
+    expect(!!(await client.fetchMetadata(itemMetadata)).collection.verified).to
+      .be.true;
+    await client.vaultUnverifyCollection(
+      item.publicKey,
+      vaultedCollection.publicKey,
+      collectionMint.publicKey,
+      otherCreator,
+      user
+    );
+    expect(!!(await client.fetchMetadata(itemMetadata)).collection.verified).to
+      .be.false;
+  });
+
+  it("Multisig applies a proposed change once enough owners approved it", async () => {
+    const { multisig } = await client.createMultisig(
+      [user.publicKey, otherCreator.publicKey, rando.publicKey],
+      2,
+      user
+    );
+
+    const mint = Keypair.generate();
+    const { metadata } = await client.generateMetadata(
+      mint,
+      generateMetadata(
+        "multisig",
+        "MSIG",
+        "uri21",
+        0,
+        user.publicKey,
+        defaultCreators,
+        multisig
+      ),
+      user
+    );
+
+    const { proposal } = await client.proposeMultisigUpdate(
+      multisig,
+      mint.publicKey,
+      { uri: { "0": "uri22", "1": null } },
+      user
+    );
+    // only the proposer approved so far
+    await expectThrowsAsync(() =>
+      client.executeMultisigUpdate(multisig, proposal)
+    );
+
+    await client.approveMultisigUpdate(multisig, proposal, otherCreator);
+    await client.executeMultisigUpdate(multisig, proposal);
+    expect((await client.fetchMetadata(metadata)).data.uri).to.be.equal(
+      "uri22"
+    );
+
+    // handing the update authority back moves the metadata out of the multisig
+    const { proposal: release } = await client.proposeMultisigUpdate(
+      multisig,
+      mint.publicKey,
+      { updateAuthority: { "0": user.publicKey } },
+      rando
+    );
+    await client.approveMultisigUpdate(multisig, release, user);
+    await client.executeMultisigUpdate(multisig, release);
+    expect((await client.fetchMetadata(metadata)).updateAuthority).to.be.equal(
+      user.publicKey.toBase58()
+    );
+  });
+
+  it("Timelock only applies a queued change once its delay passed", async () => {
+    const collectionMint = Keypair.generate();
+    await client.generateMetadata(
+      collectionMint,
+      generateMetadata("timelock", "TLCK", "uri23", 0, user.publicKey),
+      user
+    );
+
+    // only the collection's update authority can create its timelock
+    await expectThrowsAsync(() =>
+      client.initializeTimelock(collectionMint.publicKey, 1, rando, user)
+    );
+    const { timelock } = await client.initializeTimelock(
+      collectionMint.publicKey,
+      1,
+      user,
+      user
+    );
+
+    const mint = Keypair.generate();
+    const metadataInfo = generateMetadata(
+      "timelocked",
+      "TLCK",
+      "uri24",
+      0,
+      user.publicKey,
+      defaultCreators,
+      timelock
+    );
+    metadataInfo.collection = {
+      verified: false,
+      key: collectionMint.publicKey,
+    };
+    const { metadata } = await client.generateMetadata(
+      mint,
+      metadataInfo,
+      user
+    );
+
+    // a queued change can be dropped before it's executed
+    await client.queueMetadataUpdate(
+      mint.publicKey,
+      collectionMint.publicKey,
+      { uri: { "0": "uri25", "1": null } },
+      user
+    );
+    await client.cancelMetadataUpdate(
+      mint.publicKey,
+      collectionMint.publicKey,
+      user
+    );
+
+    await client.queueMetadataUpdate(
+      mint.publicKey,
+      collectionMint.publicKey,
+      { uri: { "0": "uri26", "1": null } },
+      user
+    );
+    await expectThrowsAsync(() => client.executeMetadataUpdate(mint.publicKey));
+
+    await sleep(3000);
+    await client.executeMetadataUpdate(mint.publicKey);
+    expect((await client.fetchMetadata(metadata)).data.uri).to.be.equal(
+      "uri26"
+    );
+
+    // the delay can only be raised
+    await expectThrowsAsync(() =>
+      client.setTimelockDelay(collectionMint.publicKey, 0, user)
+    );
+    await client.setTimelockDelay(collectionMint.publicKey, 60, user);
+  });
+
+  it("Metadata history records the previous state on every update", async () => {
+    const mint = Keypair.generate();
+    await client.generateMetadata(
+      mint,
+      generateMetadata("history", "HIST", "uri27", 0, user.publicKey),
+      user
+    );
+
+    // updates still go through before the mint opted into history
+    await client.updateMetadataUri(mint.publicKey, "uri28", user);
+
+    const { history } = await client.initializeMetadataHistory(
+      mint.publicKey,
+      rando
+    );
+    await client.updateMetadataUri(mint.publicKey, "uri29", user);
+    await client.patchMetadata(mint.publicKey, { name: "history2" }, user);
+
+    // the actor is the key behind the update, not the update authority it replaced
+    await client.proposeUpdateAuthority(mint.publicKey, rando.publicKey, user);
+    await client.acceptUpdateAuthority(mint.publicKey, rando);
+
+    // without the history account the update goes through unrecorded
+    await client.program.rpc.updateMetadataUri("uri30", null, {
+      accounts: {
+        updateAuthority: rando.publicKey,
+        metadata: await client.getMetadata(mint.publicKey),
+        tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
+        unpaused: await client.unpausedAccounts(),
+      },
+      signers: [rando],
+    });
+
+    const _history = await client.program.account.metadataHistory.fetch(
+      history
+    );
+    expect(_history.mint.toBase58()).to.be.equal(mint.publicKey.toBase58());
+    expect(_history.revisionCount.toNumber()).to.be.equal(3);
+    expect(_history.revisions[0].actor.toBase58()).to.be.equal(
+      user.publicKey.toBase58()
+    );
+    expect(_history.revisions[1].revision.toNumber()).to.be.equal(1);
+    expect(_history.revisions[2].actor.toBase58()).to.be.equal(
+      rando.publicKey.toBase58()
+    );
+  });
+
+  it("Only the admin can pause the program, which blocks every other instruction", async () => {
+    await expectThrowsAsync(() =>
+      client.initializeConfig(
+        {
+          treasury: rando.publicKey,
+          generateFeeLamports: new anchor.BN(0),
+          generateFeeTokens: new anchor.BN(0),
+          feeWaivers: [],
+        },
+        rando
+      )
+    );
+    await expectThrowsAsync(() => client.updateConfig({ paused: true }, rando));
+
+    await client.updateConfig({ paused: true }, _provider.wallet.publicKey);
+    const mint = Keypair.generate();
+    const metadataInfo = generateMetadata(
+      "paused",
+      "PAUS",
+      "uri30",
+      0,
+      user.publicKey
+    );
+    await expectThrowsAsync(() =>
+      client.generateMetadata(mint, metadataInfo, user)
+    );
+
+    await client.updateConfig({ paused: false }, _provider.wallet.publicKey);
+    await client.generateMetadata(mint, metadataInfo, user);
+  });
+
+  it("Generate pays the fee to the treasury unless the update authority is waived", async () => {
+    const admin = _provider.wallet.publicKey;
+    const treasury = Keypair.generate().publicKey;
+    const fee = LAMPORTS_PER_SOL / 100;
+    await client.updateConfig(
+      { treasury, generateFeeLamports: new anchor.BN(fee) },
+      admin
+    );
+
+    await client.generateMetadata(
+      Keypair.generate(),
+      generateMetadata("fee", "FEE", "uri31", 0, user.publicKey),
+      user
+    );
+    expect(await _provider.connection.getBalance(treasury)).to.be.equal(fee);
+
+    await client.updateConfig({ feeWaivers: [user.publicKey] }, admin);
+    await client.generateMetadata(
+      Keypair.generate(),
+      generateMetadata("waived", "FEE", "uri32", 0, user.publicKey),
+      user
+    );
+    expect(await _provider.connection.getBalance(treasury)).to.be.equal(fee);
+
+    await client.updateConfig(
+      { treasury: admin, generateFeeLamports: new anchor.BN(0), feeWaivers: [] },
+      admin
+    );
+  });
+
+  it("Generate can pay the fee in the fee mint instead of lamports", async () => {
+    const admin = _provider.wallet.publicKey;
+    const treasury = Keypair.generate();
+    const fee = 1000;
+    const feeMint = await Token.createMint(
+      _provider.connection,
+      user,
+      user.publicKey,
+      null,
+      0,
+      TOKEN_PROGRAM_ID
+    );
+    const payerTokenAccount = await feeMint.createAssociatedTokenAccount(
+      user.publicKey
+    );
+    const treasuryTokenAccount = await feeMint.createAssociatedTokenAccount(
+      treasury.publicKey
+    );
+    await feeMint.mintTo(payerTokenAccount, user, [], fee);
+
+    const metadataInfo = generateMetadata(
+      "token fee",
+      "FEE",
+      "uri33",
+      0,
+      user.publicKey
+    );
+    // no fee mint configured yet
+    await expectThrowsAsync(() =>
+      client.generateMetadata(Keypair.generate(), metadataInfo, user, {
+        token: {},
+      })
+    );
+
+    await client.updateConfig(
+      {
+        treasury: treasury.publicKey,
+        generateFeeLamports: new anchor.BN(LAMPORTS_PER_SOL / 100),
+        feeMint: feeMint.publicKey,
+        generateFeeTokens: new anchor.BN(fee),
+      },
+      admin
+    );
+    await client.generateMetadata(Keypair.generate(), metadataInfo, user, {
+      token: {},
+    });
+
+    const _treasuryTokenAccount = await feeMint.getAccountInfo(
+      treasuryTokenAccount
+    );
+    expect(_treasuryTokenAccount.amount.toNumber()).to.be.equal(fee);
+    expect(
+      await _provider.connection.getBalance(treasury.publicKey)
+    ).to.be.equal(0);
+
+    await client.updateConfig(
+      {
+        treasury: admin,
+        generateFeeLamports: new anchor.BN(0),
+        generateFeeTokens: new anchor.BN(0),
+      },
+      admin
+    );
+  });
+
+  it("Template authority generates nfts from the collection defaults", async () => {
+    const collectionMint = Keypair.generate();
+    await client.generateMetadata(
+      collectionMint,
+      generateMetadata("template", "TMPL", "uri34", 0, user.publicKey),
+      user
+    );
+
+    const template = {
+      symbol: "TMPL",
+      creators: defaultCreators,
+      sellerFeeBasisPoints: 500,
+      updateAuthorityIsSigner: true,
+      isMutable: true,
+      collection: { verified: false, key: collectionMint.publicKey },
+      uses: null,
+      supply: { limited: { maxSupply: new u64(0) } },
+      newUpdateAuthority: null,
+    };
+    // only the collection's update authority can create its template
+    await expectThrowsAsync(() =>
+      client.initializeCollectionTemplate(
+        collectionMint.publicKey,
+        template,
+        rando,
+        user
+      )
+    );
+    await client.initializeCollectionTemplate(
+      collectionMint.publicKey,
+      template,
+      user,
+      user
+    );
+
+    await expectThrowsAsync(() =>
+      client.generateMetadataFromTemplate(
+        Keypair.generate(),
+        collectionMint.publicKey,
+        "from template",
+        "uri35",
+        {},
+        rando
+      )
+    );
+    const { metadata } = await client.generateMetadataFromTemplate(
+      Keypair.generate(),
+      collectionMint.publicKey,
+      "from template",
+      "uri35",
+      { sellerFeeBasisPoints: 250 },
+      user
+    );
+
+    const _metadata = await client.fetchMetadata(metadata);
+    expect(_metadata.data.name).to.be.equal("from template");
+    expect(_metadata.data.uri).to.be.equal("uri35");
+    expect(_metadata.data.symbol).to.be.equal("TMPL");
+    expect(_metadata.data.sellerFeeBasisPoints).to.be.equal(250);
+    expect(_metadata.data.creators.length).to.be.equal(2);
+
+    await expectThrowsAsync(() =>
+      client.updateCollectionTemplate(
+        collectionMint.publicKey,
+        { ...template, symbol: "NEW" },
+        rando
+      )
+    );
+    await client.updateCollectionTemplate(
+      collectionMint.publicKey,
+      { ...template, symbol: "NEW" },
+      user
+    );
+    const { metadata: updated } = await client.generateMetadataFromTemplate(
+      Keypair.generate(),
+      collectionMint.publicKey,
+      "updated template",
+      "uri36",
+      {},
+      user
+    );
+    expect((await client.fetchMetadata(updated)).data.symbol).to.be.equal(
+      "NEW"
+    );
+  });
+
+  it("Numbered generate names nfts from the collection counter until max supply", async () => {
+    const collectionMint = Keypair.generate();
+    await client.generateMetadata(
+      collectionMint,
+      generateMetadata("numbered", "NUMB", "uri37", 0, user.publicKey),
+      user
+    );
+    const template = {
+      symbol: "NUMB",
+      creators: defaultCreators,
+      sellerFeeBasisPoints: 420,
+      updateAuthorityIsSigner: true,
+      isMutable: true,
+      collection: { verified: false, key: collectionMint.publicKey },
+      uses: null,
+      supply: { limited: { maxSupply: new u64(0) } },
+      newUpdateAuthority: null,
+    };
+
+    // only the collection's update authority can create its counter
+    await expectThrowsAsync(() =>
+      client.initializeCollectionCounter(
+        collectionMint.publicKey,
+        2,
+        "Ape",
+        "https://someserver.com/apes/",
+        rando,
+        user
+      )
+    );
+    const { counter } = await client.initializeCollectionCounter(
+      collectionMint.publicKey,
+      2,
+      "Ape",
+      "https://someserver.com/apes/",
+      user,
+      user
+    );
+
+    // numbered nfts have to be part of the counter's collection
+    await expectProgramErrorAsync(
+      () =>
+        client.generateNumberedMetadata(
+          Keypair.generate(),
+          collectionMint.publicKey,
+          { ...template, collection: null },
+          user
+        ),
+      "Metadata does not belong to the collection"
+    );
+
+    for (const number of [1, 2]) {
+      const { metadata } = await client.generateNumberedMetadata(
+        Keypair.generate(),
+        collectionMint.publicKey,
+        template,
+        user
+      );
+
+      const _metadata = await client.fetchMetadata(metadata);
+      expect(_metadata.data.name).to.be.equal(`Ape #${number}`);
+      expect(_metadata.data.uri).to.be.equal(
+        `https://someserver.com/apes/${number}.json`
+      );
+      expect(_metadata.data.symbol).to.be.equal("NUMB");
+    }
+
+    const _counter = await client.program.account.collectionCounter.fetch(
+      counter
+    );
+    expect(_counter.count.toNumber()).to.be.equal(2);
+    await expectThrowsAsync(() =>
+      client.generateNumberedMetadata(
+        Keypair.generate(),
+        collectionMint.publicKey,
+        template,
+        user
+      )
+    );
+  });
+
+  it("Reveal only sets the committed uri, anyone can crank it after the reveal time", async () => {
+    const collectionMint = Keypair.generate();
+    await client.generateMetadata(
+      collectionMint,
+      generateMetadata("reveal", "RVL", "uri38", 0, user.publicKey),
+      user
+    );
+    const [commitment] = await client.getRevealCommitment(
+      collectionMint.publicKey
+    );
+
+    const mints = [Keypair.generate(), Keypair.generate()];
+    for (const mint of mints) {
+      const metadataInfo = generateMetadata(
+        "hidden",
+        "RVL",
+        "placeholder",
+        0,
+        user.publicKey,
+        defaultCreators,
+        commitment
+      );
+      metadataInfo.collection = {
+        verified: false,
+        key: collectionMint.publicKey,
+      };
+      await client.generateMetadata(mint, metadataInfo, user);
+    }
+    const uris = ["uri39", "uri40"];
+    const leaves = mints.map((mint, i) =>
+      revealLeaf(mint.publicKey, uris[i])
+    );
+    const root = hashMerkleNodes(leaves[0], leaves[1]);
+
+    // only the collection's update authority can commit to its uris
+    const revealAt = Math.floor(Date.now() / 1000) + 3;
+    await expectThrowsAsync(() =>
+      client.commitReveal(collectionMint.publicKey, root, revealAt, rando)
+    );
+    await client.commitReveal(collectionMint.publicKey, root, revealAt, user);
+
+    // anyone but the authority has to wait for the reveal time
+    await expectThrowsAsync(() =>
+      client.reveal(
+        mints[0].publicKey,
+        collectionMint.publicKey,
+        uris[0],
+        [leaves[1]],
+        rando
+      )
+    );
+    // a uri that wasn't committed is rejected
+    await expectThrowsAsync(() =>
+      client.reveal(
+        mints[0].publicKey,
+        collectionMint.publicKey,
+        "swapped",
+        [leaves[1]],
+        user
+      )
+    );
+    await client.reveal(
+      mints[0].publicKey,
+      collectionMint.publicKey,
+      uris[0],
+      [leaves[1]],
+      user
+    );
+    const metadata = await client.getMetadata(mints[0].publicKey);
+    expect((await client.fetchMetadata(metadata)).data.uri).to.be.equal(
+      uris[0]
+    );
+
+    // unrevealed metadata stays with the commitment
+    await expectThrowsAsync(() =>
+      client.releaseRevealAuthority(
+        mints[1].publicKey,
+        collectionMint.publicKey,
+        user.publicKey,
+        [leaves[0]],
+        user
+      )
+    );
+    await client.releaseRevealAuthority(
+      mints[0].publicKey,
+      collectionMint.publicKey,
+      user.publicKey,
+      [leaves[1]],
+      user
+    );
+    expect((await client.fetchMetadata(metadata)).updateAuthority).to.be.equal(
+      user.publicKey.toBase58()
+    );
+
+    await sleep(4000);
+    await client.reveal(
+      mints[1].publicKey,
+      collectionMint.publicKey,
+      uris[1],
+      [leaves[0]],
+      rando
+    );
+    expect(
+      (await client.fetchMetadata(await client.getMetadata(mints[1].publicKey)))
+        .data.uri
+    ).to.be.equal(uris[1]);
+  });
+
+  it("Only allowlisted payers generate into the collection, up to their limit", async () => {
+    const collectionMint = Keypair.generate();
+    await client.generateMetadata(
+      collectionMint,
+      generateMetadata("allowlist", "ALST", "uri41", 0, user.publicKey),
+      user
+    );
+
+    // otherCreator can mint once, rando without limit
+    const leaves = [
+      allowlistLeaf(otherCreator.publicKey, 1),
+      allowlistLeaf(rando.publicKey, 0),
+    ];
+    const root = hashMerkleNodes(leaves[0], leaves[1]);
+
+    // only the collection's update authority can create its allowlist
+    await expectThrowsAsync(() =>
+      client.initializeAllowlist(collectionMint.publicKey, root, rando, user)
+    );
+    await client.initializeAllowlist(
+      collectionMint.publicKey,
+      root,
+      user,
+      user
+    );
+
+    // the allowlist signs as update authority, so no creator can be verified on mint
+    const metadataInfo = (uri: string) => {
+      const info = generateMetadata(
+        "allowed",
+        "ALST",
+        uri,
+        0,
+        user.publicKey,
+        generateDefaultCreators(user.publicKey).map((c) => ({
+          ...c,
+          verified: false,
+        })),
+        rando.publicKey
+      );
+      info.collection = { verified: false, key: collectionMint.publicKey };
+      return info;
+    };
+    const generateAllowlisted = (
+      uri: string,
+      maxMints: number,
+      proof: Buffer[],
+      payer: Keypair
+    ) =>
+      client.generateMetadataAllowlisted(
+        Keypair.generate(),
+        metadataInfo(uri),
+        maxMints,
+        proof,
+        payer
+      );
+
+    // the limit is part of the leaf, so it can't be raised by the payer
+    await expectThrowsAsync(() =>
+      generateAllowlisted("uri42", 0, [leaves[1]], otherCreator)
+    );
+    // claimers don't need a co-signer, and can't pick the update authority
+    const { metadata, tokenAccount } = await generateAllowlisted(
+      "uri42",
+      1,
+      [leaves[1]],
+      otherCreator
+    );
+    const _metadata = await client.fetchMetadata(metadata);
+    expect(_metadata.updateAuthority).to.be.equal(user.publicKey.toBase58());
+    const balance = await _provider.connection.getTokenAccountBalance(
+      tokenAccount
+    );
+    expect(balance.value.amount).to.be.equal("1");
+    await expectThrowsAsync(() =>
+      generateAllowlisted("uri43", 1, [leaves[1]], otherCreator)
+    );
+
+    // without a master edition the mint would stay with the allowlist
+    await expectProgramErrorAsync(
+      () =>
+        client.generateMetadataAllowlisted(
+          Keypair.generate(),
+          { ...metadataInfo("uri44"), supply: { none: {} } },
+          0,
+          [leaves[0]],
+          rando
+        ),
+      "Allowlisted nfts need a master edition; the supply can't be none"
+    );
+    await generateAllowlisted("uri44", 0, [leaves[0]], rando);
+    await generateAllowlisted("uri45", 0, [leaves[0]], rando);
+
+    // replacing the root drops rando from the list
+    await client.updateAllowlistRoot(collectionMint.publicKey, leaves[0], user);
+    await expectThrowsAsync(() =>
+      generateAllowlisted("uri46", 0, [leaves[0]], rando)
+    );
+  });
+
+  it("Buyers mint from a drop without a co-signer while it's live and not sold out", async () => {
+    const collectionMint = Keypair.generate();
+    await client.generateMetadata(
+      collectionMint,
+      generateMetadata("drop", "DROP", "uri47", 0, user.publicKey),
+      user
+    );
+    // the drop signs as update authority, so no creator can be verified on mint
+    const template = {
+      symbol: "DROP",
+      creators: generateDefaultCreators(user.publicKey).map((c) => ({
+        ...c,
+        verified: false,
+      })),
+      sellerFeeBasisPoints: 420,
+      updateAuthorityIsSigner: true,
+      isMutable: true,
+      collection: { verified: false, key: collectionMint.publicKey },
+      uses: null,
+      supply: { limited: { maxSupply: new u64(0) } },
+      newUpdateAuthority: null,
+    };
+    await client.initializeCollectionTemplate(
+      collectionMint.publicKey,
+      template,
+      user,
+      user
+    );
+
+    const treasury = Keypair.generate().publicKey;
+    const price = LAMPORTS_PER_SOL / 10;
+    const now = Math.floor(Date.now() / 1000);
+    const info = {
+      treasury,
+      priceLamports: new anchor.BN(price),
+      goLiveAt: new anchor.BN(now + 3600),
+      endAt: new anchor.BN(now + 7200),
+      maxItems: new anchor.BN(1),
+      namePrefix: "Drop",
+      baseUri: "https://someserver.com/drop",
+    };
+    // only the collection's update authority can create its drop
+    await expectThrowsAsync(() =>
+      client.initializeDrop(collectionMint.publicKey, info, rando, user)
+    );
+    await client.initializeDrop(collectionMint.publicKey, info, user, user);
+
+    await expectThrowsAsync(() =>
+      client.mintFromDrop(Keypair.generate(), collectionMint.publicKey, rando)
+    );
+    const live = { ...info, goLiveAt: new anchor.BN(now - 60) };
+    await expectThrowsAsync(() =>
+      client.updateDrop(collectionMint.publicKey, live, rando)
+    );
+    await client.updateDrop(collectionMint.publicKey, live, user);
+
+    // items without a master edition would leave the mint authority with the drop
+    await client.updateCollectionTemplate(
+      collectionMint.publicKey,
+      { ...template, supply: { none: {} } },
+      user
+    );
+    await expectThrowsAsync(() =>
+      client.mintFromDrop(Keypair.generate(), collectionMint.publicKey, rando)
+    );
+    await client.updateCollectionTemplate(
+      collectionMint.publicKey,
+      template,
+      user
+    );
+
+    const { metadata, tokenAccount } = await client.mintFromDrop(
+      Keypair.generate(),
+      collectionMint.publicKey,
+      rando
+    );
+    expect(await _provider.connection.getBalance(treasury)).to.be.equal(price);
+    const _metadata = await client.fetchMetadata(metadata);
+    expect(_metadata.data.name).to.be.equal("Drop #1");
+    expect(_metadata.data.uri).to.be.equal("https://someserver.com/drop/1.json");
+    expect(_metadata.updateAuthority).to.be.equal(user.publicKey.toBase58());
+    expect(!!_metadata.primarySaleHappened).to.be.equal(true);
+    const balance = await _provider.connection.getTokenAccountBalance(
+      tokenAccount
+    );
+    expect(balance.value.amount).to.be.equal("1");
+
+    await expectThrowsAsync(() =>
+      client.mintFromDrop(Keypair.generate(), collectionMint.publicKey, rando)
+    );
+  });
+
+  it("Drops only mint from a template set up by the drop authority", async () => {
+    const collectionMint = Keypair.generate();
+    await client.generateMetadata(
+      collectionMint,
+      generateMetadata("drop2", "DROP", "uri55", 0, user.publicKey),
+      user
+    );
+    await client.initializeCollectionTemplate(
+      collectionMint.publicKey,
+      {
+        symbol: "DROP",
+        creators: null,
+        sellerFeeBasisPoints: 420,
+        updateAuthorityIsSigner: true,
+        isMutable: true,
+        collection: { verified: false, key: collectionMint.publicKey },
+        uses: null,
+        supply: { limited: { maxSupply: new u64(0) } },
+        newUpdateAuthority: null,
+      },
+      user,
+      user
+    );
+
+    // the collection changes hands after its template was set up
+    await client.proposeUpdateAuthority(
+      collectionMint.publicKey,
+      rando.publicKey,
+      user
+    );
+    await client.acceptUpdateAuthority(collectionMint.publicKey, rando);
+
+    const now = Math.floor(Date.now() / 1000);
+    await client.initializeDrop(
+      collectionMint.publicKey,
+      {
+        treasury: rando.publicKey,
+        priceLamports: new anchor.BN(0),
+        goLiveAt: new anchor.BN(now - 60),
+        endAt: new anchor.BN(now + 3600),
+        maxItems: new anchor.BN(1),
+        namePrefix: "Drop",
+        baseUri: "https://someserver.com/drop2",
+      },
+      rando,
+      rando
+    );
+
+    await expectProgramErrorAsync(
+      () =>
+        client.mintFromDrop(Keypair.generate(), collectionMint.publicKey, user),
+      "Template was not set up by the authority of the drop"
+    );
+  });
+
+  it("Content hash is stored on generate and update, and cleared when the uri changes without one", async () => {
+    const mint = Keypair.generate();
+    const json = hashContent('{"name":"hashed"}');
+    const metadataInfo = {
+      ...generateMetadata("hashed", "HASH", "uri48", 0, user.publicKey),
+      contentHash: json,
+    };
+
+    // a hash can only be stored once the content hash account exists
+    await expectThrowsAsync(() =>
+      client.generateMetadata(Keypair.generate(), metadataInfo, user)
+    );
+    const { contentHash } = await client.initializeContentHash(
+      mint.publicKey,
+      rando
+    );
+    await client.generateMetadata(mint, metadataInfo, user);
+
+    let _contentHash = await client.program.account.contentHash.fetch(
+      contentHash
+    );
+    expect(_contentHash.mint.toBase58()).to.be.equal(
+      mint.publicKey.toBase58()
+    );
+    expect(_contentHash.hash).to.deep.equal(json);
+
+    // the hash still describes the json when the uri is kept
+    await client.patchMetadata(mint.publicKey, { name: "hashed2" }, user);
+    _contentHash = await client.program.account.contentHash.fetch(contentHash);
+    expect(_contentHash.hash).to.deep.equal(json);
+
+    const json2 = hashContent('{"name":"hashed2"}');
+    await client.updateMetadataUri(mint.publicKey, "uri49", user, json2);
+    _contentHash = await client.program.account.contentHash.fetch(contentHash);
+    expect(_contentHash.hash).to.deep.equal(json2);
+
+    await client.updateMetadataUri(mint.publicKey, "uri50", user);
+    _contentHash = await client.program.account.contentHash.fetch(contentHash);
+    expect(_contentHash.hash).to.be.null;
+
+    // the account is only required when a hash is given
+    const accounts = {
+      updateAuthority: user.publicKey,
+      metadata: await client.getMetadata(mint.publicKey),
+      tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
+      unpaused: await client.unpausedAccounts(),
+    };
+    await expectProgramErrorAsync(
+      () =>
+        client.program.rpc.updateMetadataUri("uri53", json, {
+          accounts,
+          signers: [user],
+        }),
+      "Content hash account of the mint must be passed as a remaining account"
+    );
+    await client.program.rpc.updateMetadataUri("uri53", null, {
+      accounts,
+      signers: [user],
+    });
+  });
+
+  it("Content hash can be stored when the mint is created by generate", async () => {
+    const mint = Keypair.generate();
+    const json = hashContent('{"name":"hashed3"}');
+    const metadataInfo = {
+      ...generateMetadata("hashed3", "HASH", "uri54", 0, user.publicKey),
+      contentHash: json,
+    };
+
+    // the mint doesn't exist yet, only its key seeds the content hash account
+    const { contentHash } = await client.initializeContentHash(
+      mint.publicKey,
+      rando
+    );
+    await client.generateMetadataWithMint(
+      mint,
+      rando.publicKey,
+      metadataInfo,
+      user
+    );
+
+    const _contentHash = await client.program.account.contentHash.fetch(
+      contentHash
+    );
+    expect(_contentHash.mint.toBase58()).to.be.equal(
+      mint.publicKey.toBase58()
+    );
+    expect(_contentHash.hash).to.deep.equal(json);
+  });
+
+  it("Generate fails with wrapper errors for mints that aren't nfts or already have metadata", async () => {
+    const metadataInfo = {
+      ...generateMetadata("errors", "ERR", "uri51", null, user.publicKey),
+      contentHash: null,
+    };
+
+    const mint = Keypair.generate();
+    const { accounts, signers } = await client.generateMetadataAccounts(
+      mint,
+      user
+    );
+    await expectProgramErrorAsync(
+      () =>
+        client.program.rpc.generate(metadataInfo as any, { lamports: {} }, {
+          accounts,
+          preInstructions: await client.generateMintInstructions(
+            mint.publicKey,
+            user.publicKey,
+            user.publicKey,
+            user.publicKey,
+            null
+          ),
+          signers: [...signers, mint],
+        }),
+      "Mint must have a freeze authority"
+    );
+
+    // without a master edition, the mint keeps its authorities and passes the context checks
+    const existingMint = Keypair.generate();
+    await client.generateMetadata(existingMint, metadataInfo, user);
+    const existing = await client.generateMetadataAccounts(existingMint, user);
+    await expectProgramErrorAsync(
+      () =>
+        client.program.rpc.generate(metadataInfo as any, { lamports: {} }, {
+          accounts: existing.accounts,
+          signers: existing.signers,
+        }),
+      "Metadata account already exists for this mint"
+    );
+  });
+
+  it("Generate only accepts the metadata and master edition derived from the mint", async () => {
+    const metadataInfo = {
+      ...generateMetadata("derived", "DRV", "uri52", 0, user.publicKey),
+      contentHash: null,
+    };
+    const mint = Keypair.generate();
+    const { accounts, signers } = await client.generateMetadataAccounts(
+      mint,
+      user
+    );
+    const generate = async (overrides: object) =>
+      client.program.rpc.generate(metadataInfo as any, { lamports: {} }, {
+        accounts: { ...accounts, ...overrides },
+        preInstructions: await client.generateMintInstructions(
+          mint.publicKey,
+          user.publicKey,
+          user.publicKey,
+          user.publicKey,
+          user.publicKey
+        ),
+        signers: [...signers, mint],
+      });
+
+    const otherMasterEdition = await client.getMasterEdition(
+      Keypair.generate().publicKey
+    );
+    await expectProgramErrorAsync(
+      () => generate({ metadata: accounts.masterEdition }),
+      "Metadata account must match seeds ['metadata', metadata program id, mint]"
+    );
+    await expectProgramErrorAsync(
+      () => generate({ masterEdition: otherMasterEdition }),
+      "Master edition account must match seeds ['metadata', metadata program id, mint, 'edition']"
+    );
+
+    await generate({});
+    const _metadata = await client.fetchMetadata(accounts.metadata);
+    expect(_metadata.mint).to.be.equal(mint.publicKey.toBase58());
+  });
 });
@@ -15,3 +15,21 @@ export const expectThrowsAsync = async (
     expect((error as any).message).to.equal(errorMessage);
   }
 };
+
+export const sleep = (ms: number) =>
+  new Promise((resolve) => setTimeout(resolve, ms));
+
+// anchor surfaces errors of the wrapper with the #[msg] of their error code
+export const expectProgramErrorAsync = async (
+  method: () => Promise<any>,
+  errorMsg: string
+) => {
+  let error: unknown = null;
+  try {
+    await method();
+  } catch (err: unknown) {
+    error = err;
+  }
+  expect(error).to.be.an("Error");
+  expect((error as any).msg).to.equal(errorMsg);
+};